@@ -1,24 +1,46 @@
 use gloo_timers::future::TimeoutFuture;
 use stylist::yew::styled_component;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::console;
 use yew::prelude::*;
 
 use crate::components::charts::comparison_chart::ComparisonChart;
 use crate::components::charts::distribution_chart::DistributionChart;
+use crate::components::charts::lorenz_chart::LorenzChart;
 use crate::components::charts::statistics_chart::StatisticsChart;
+use crate::components::charts::trendline_chart::TrendlineChart;
+use crate::components::charts::wealth_boxplot::WealthBoxPlot;
+use crate::components::charts::wealth_treemap::WealthTreemap;
+use crate::components::display::copy_to_clipboard::CopyToClipboard;
+use crate::components::display::qr_code::QrCode;
+use crate::components::ui::currency_selector::CurrencySelector;
+use crate::components::ui::export_menu::ExportMenu;
 use crate::components::ui::footer::Footer;
 use crate::components::ui::header::Header;
 use crate::components::ui::loading_spinner::{LoadingSpinner, SpinnerSize};
+use crate::components::ui::refresh_control::RefreshControl;
 use crate::services::bitcoin_api::BitcoinApiService;
 use crate::services::data_processor::DataProcessor;
 use crate::services::percentile_calculator::PercentileCalculator;
+use crate::services::price_stream::LivePriceStream;
 use crate::types::bitcoin::{BitcoinDistribution, PercentileResult};
+use crate::types::currency::Currency;
+use crate::types::portfolio::PortfolioEntry;
+use crate::utils::percentile_history::PercentileHistoryStorage;
+use crate::utils::portfolio_storage::PortfolioStorage;
 use crate::utils::formatters::{
-    format_large_number, format_number_with_commas, format_rank,
+    format_currency_amount, format_large_number, format_number_with_commas, format_rank,
 };
+use crate::utils::chart_theme::{ChartPalette, ChartThemeConfig};
 use crate::utils::theme::{Theme, ThemeManager};
 
+/// How often the live price poll re-fetches `fetch_bitcoin_price()`
+const PRICE_POLL_INTERVAL_MS: u32 = 30_000;
+/// Re-fetch the full distribution once every this-many price polls (~5 minutes at the interval
+/// above), since it changes far less often than the spot price
+const DISTRIBUTION_POLL_EVERY_N_POLLS: u32 = 10;
+
 #[derive(Clone, PartialEq)]
 pub enum AppState {
     Loading,
@@ -26,6 +48,8 @@ pub enum AppState {
         distribution: BitcoinDistribution,
         bitcoin_price: f64,
         network_stats: std::collections::HashMap<String, f64>,
+        /// BTC price in every supported fiat currency, keyed by `Currency::code()`
+        exchange_rates: std::collections::HashMap<String, f64>,
     },
     Error {
         message: String,
@@ -46,6 +70,160 @@ pub struct AppData {
     pub is_calculating: bool,
     pub wealth_analysis: Option<std::collections::HashMap<String, f64>>,
     pub percentile_thresholds: Option<Vec<(f64, f64)>>,
+    /// Fiat currency holdings values are converted into for display; BTC stays canonical
+    pub selected_currency: Currency,
+    /// Labeled holdings (e.g. "cold storage", "exchange"), persisted via `PortfolioStorage` and
+    /// combined by `on_calculate_portfolio` into a single aggregate percentile calculation
+    pub portfolio_entries: Vec<PortfolioEntry>,
+    /// Each portfolio entry's share of the last aggregate calculation, aligned by index with
+    /// `portfolio_entries`; cleared whenever the portfolio changes so a stale breakdown can't
+    /// be shown against entries it wasn't computed from
+    pub portfolio_contribution_shares: Option<Vec<f64>>,
+}
+
+/// Build the multi-line "Copy my result" clipboard summary: BTC amount, satoshis, global
+/// percentile, rank, and approximate USD value at the current `bitcoin_price`.
+fn build_result_summary(result: &PercentileResult, bitcoin_price: f64) -> String {
+    format!(
+        "My Bitcoin Rank\n{} BTC ({} sats)\nPercentile: {:.2}%\nRank: {}\n≈ ${} USD",
+        format!("{:.8}", result.user_bitcoin_amount),
+        format_number_with_commas(result.user_bitcoin_amount * 100_000_000.0),
+        result.percentile,
+        format_rank(result.rank),
+        format_number_with_commas(result.user_bitcoin_amount * bitcoin_price),
+    )
+}
+
+/// Trigger a browser download of in-memory text content (CSV, JSON, etc.) as `filename`, the same
+/// blob-download approach the chart components use for their image exports.
+fn trigger_text_download(content: &str, filename: &str, mime_type: &str) -> Result<(), wasm_bindgen::JsValue> {
+    let parts = js_sys::Array::new();
+    parts.push(&wasm_bindgen::JsValue::from_str(content));
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .ok_or_else(|| wasm_bindgen::JsValue::from_str("No window available"))?
+        .document()
+        .ok_or_else(|| wasm_bindgen::JsValue::from_str("No document available"))?;
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| wasm_bindgen::JsValue::from_str("Failed to create anchor element"))?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    web_sys::Url::revoke_object_url(&url)?;
+    Ok(())
+}
+
+/// Read a query parameter from the page URL (e.g. `?amount=1.5` shared via ComparisonChart's
+/// "Share my position" link)
+fn get_query_param(name: &str) -> Option<String> {
+    let window = web_sys::window()?;
+    let search = window.location().search().ok()?;
+    let params = web_sys::UrlSearchParams::new_with_str(&search).ok()?;
+    params.get(name)
+}
+
+/// Build a shareable deep-link that reopens the site with `amount` prefilled and
+/// auto-calculated, via the same `amount` query parameter `get_query_param` reads on load
+fn build_deep_link_url(amount: f64) -> Option<String> {
+    let location = web_sys::window()?.location();
+    let origin = location.origin().ok()?;
+    let pathname = location.pathname().ok()?;
+    let encoded_amount = js_sys::encode_uri_component(&format!("{}", amount));
+    Some(format!("{}{}?amount={}", origin, pathname, encoded_amount))
+}
+
+/// Keep the browser address bar in sync with the current result via `History::replace_state`,
+/// so bookmarking or reloading the page reopens at the same computed position
+fn sync_deep_link_url(amount: f64) {
+    let Some(url) = build_deep_link_url(amount) else {
+        return;
+    };
+    let Some(history) = web_sys::window().and_then(|w| w.history().ok()) else {
+        return;
+    };
+    if let Err(e) = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url)) {
+        console::log_1(&format!("❌ Failed to update address bar: {:?}", e).into());
+    }
+}
+
+/// Kick off a percentile calculation for `user_input` against `distribution`, the same work
+/// `on_calculate` runs, shared with the auto-calculate-from-URL effect below
+fn spawn_percentile_calculation(
+    app_data: UseStateHandle<AppData>,
+    percentile_calculator: UseStateHandle<PercentileCalculator>,
+    distribution: BitcoinDistribution,
+    user_input: String,
+) {
+    spawn_local(async move {
+        // Set calculating state
+        app_data.set(AppData {
+            is_calculating: true,
+            ..(*app_data).clone()
+        });
+
+        // Parse user input
+        match user_input.parse::<f64>() {
+            Ok(amount) => {
+                console::log_1(&format!("🧮 Calculating percentile for {} BTC", amount).into());
+
+                // Add small delay for UX
+                TimeoutFuture::new(300).await;
+
+                // Calculate percentile and additional analysis
+                match percentile_calculator.calculate_user_percentile(amount, &distribution) {
+                    Ok(result) => {
+                        console::log_1(
+                            &format!("✅ Calculation complete: {:.2}%", result.percentile).into(),
+                        );
+
+                        // Record this snapshot so the trendline panel has real history to plot
+                        // the next time this amount is calculated, instead of only ever showing
+                        // the flat placeholder
+                        PercentileHistoryStorage::record(amount, result.percentile);
+
+                        // Calculate wealth concentration analysis
+                        let wealth_analysis = percentile_calculator
+                            .calculate_wealth_concentration(&distribution)
+                            .unwrap_or_default();
+
+                        // Calculate percentile thresholds
+                        let percentile_thresholds = percentile_calculator
+                            .calculate_percentile_thresholds(&distribution)
+                            .unwrap_or_default();
+
+                        app_data.set(AppData {
+                            calculation_result: Some(result),
+                            wealth_analysis: Some(wealth_analysis),
+                            percentile_thresholds: Some(percentile_thresholds),
+                            is_calculating: false,
+                            ..(*app_data).clone()
+                        });
+                    }
+                    Err(e) => {
+                        console::log_1(&format!("❌ Calculation failed: {}", e).into());
+                        app_data.set(AppData {
+                            is_calculating: false,
+                            ..(*app_data).clone()
+                        });
+                    }
+                }
+            }
+            Err(_) => {
+                console::log_1(&"❌ Invalid Bitcoin amount entered".into());
+                app_data.set(AppData {
+                    is_calculating: false,
+                    ..(*app_data).clone()
+                });
+            }
+        }
+    });
 }
 
 impl Default for AppData {
@@ -57,21 +235,67 @@ impl Default for AppData {
             is_calculating: false,
             wealth_analysis: None,
             percentile_thresholds: None,
+            selected_currency: Currency::default(),
+            portfolio_entries: Vec::new(),
+            portfolio_contribution_shares: None,
         }
     }
 }
 
 #[styled_component(App)]
 pub fn app() -> Html {
-    let app_data = use_state(|| AppData::default());
+    // A shared result link (see ComparisonChart's "Share my position" feature) prefills the
+    // amount input so the page reopens at the same computed position without retyping it.
+    let shared_amount = use_state(|| get_query_param("amount"));
+    let app_data = use_state(|| AppData {
+        user_input: (*shared_amount).clone().unwrap_or_default(),
+        portfolio_entries: PortfolioStorage::load(),
+        ..AppData::default()
+    });
 
     // Theme state management
     let current_theme = use_state(|| ThemeManager::get_initial_theme());
 
+    // Chart palette state management - the saved config's `extends` names the active palette;
+    // an unrecognized or absent config falls back to the default Mempool palette.
+    let current_palette = use_state(|| {
+        ThemeManager::get_stored_chart_theme_config()
+            .and_then(|config| config.extends)
+            .and_then(|name| ChartPalette::from_str(&name))
+            .unwrap_or_default()
+    });
+
+    // Whether a user-triggered refresh (pull-to-refresh or the desktop button) is in flight, as
+    // opposed to the initial `AppState::Loading` full-page load
+    let refreshing = use_state(|| false);
+
+    // When the live price/distribution data was last successfully fetched (ms since epoch), for
+    // the "last updated N seconds ago" staleness indicator
+    let last_updated = use_state(|| None::<f64>);
+    // Ticks once a second purely to re-render the staleness indicator's elapsed-time text; its
+    // value is never read otherwise
+    let staleness_tick = use_state(|| 0u32);
+
     // Initialize services
     let api_service = use_state(|| BitcoinApiService::new());
     let _data_processor = use_state(|| DataProcessor::new());
-    let percentile_calculator = use_state(|| PercentileCalculator::new());
+    // Backed by a live Kraken ticker subscription rather than the default static price, so the
+    // percentile UI's USD value estimates update in real time instead of only on the periodic
+    // `fetch_bitcoin_price()` poll below.
+    let percentile_calculator = use_state(|| {
+        PercentileCalculator::new_with_price_source(Box::new(LivePriceStream::connect(50_000.0)), 0.0)
+    });
+
+    // Whether the "Your Bitcoin Amount" card is in address-lookup mode instead of manual entry
+    let address_lookup_mode = use_state(|| false);
+    let address_input = use_state(String::new);
+    let address_lookup_error = use_state(|| None::<String>);
+    let is_looking_up_address = use_state(|| false);
+
+    // New-entry fields for the portfolio tracker's "add holding" form
+    let portfolio_label_input = use_state(String::new);
+    let portfolio_amount_input = use_state(String::new);
+    let portfolio_error = use_state(|| None::<String>);
 
     // Initialize theme on mount - only run once
     use_effect_with((), {
@@ -89,14 +313,60 @@ pub fn app() -> Html {
         }
     });
 
+    // Live-track OS color scheme changes while the user has no stored preference
+    {
+        let current_theme = current_theme.clone();
+        use_effect_with((), move |_| {
+            let current_theme = current_theme.clone();
+            let watch_result = ThemeManager::watch_system_theme(move |system_theme| {
+                console::log_1(&format!("🎨 System theme changed: {:?}", system_theme).into());
+                current_theme.set(system_theme);
+                if let Err(e) = ThemeManager::apply_theme_with_retry(system_theme) {
+                    console::log_1(
+                        &format!("❌ Failed to apply system theme change: {:?}", e).into(),
+                    );
+                }
+            });
+
+            if let Err(e) = watch_result {
+                console::log_1(&format!("❌ Failed to watch system theme: {:?}", e).into());
+            }
+            || ()
+        });
+    }
+
+    // Keep every open tab's theme in sync when the user toggles it in another one
+    {
+        let current_theme = current_theme.clone();
+        use_effect_with((), move |_| {
+            let current_theme = current_theme.clone();
+            let watch_result = ThemeManager::watch_storage_changes(move |stored_theme| {
+                console::log_1(&format!("🎨 Theme changed in another tab: {:?}", stored_theme).into());
+                current_theme.set(stored_theme);
+                if let Err(e) = ThemeManager::apply_theme_with_retry(stored_theme) {
+                    console::log_1(
+                        &format!("❌ Failed to apply theme change from another tab: {:?}", e).into(),
+                    );
+                }
+            });
+
+            if let Err(e) = watch_result {
+                console::log_1(&format!("❌ Failed to watch storage changes: {:?}", e).into());
+            }
+            || ()
+        });
+    }
+
     // Load Bitcoin distribution data on mount
     {
         let app_data_clone = app_data.clone();
         let api_service = api_service.clone();
+        let last_updated = last_updated.clone();
 
         use_effect_with((), move |_| {
             let app_data = app_data_clone.clone();
             let api_service = api_service.clone();
+            let last_updated = last_updated.clone();
 
             spawn_local(async move {
                 console::log_1(&"🚀 Starting Bitcoin distribution data load...".into());
@@ -126,15 +396,21 @@ pub fn app() -> Html {
                             api_service.fetch_bitcoin_price().await.unwrap_or(50000.0);
                         let network_stats =
                             api_service.fetch_network_stats().await.unwrap_or_default();
+                        let exchange_rates = api_service
+                            .fetch_exchange_rates()
+                            .await
+                            .unwrap_or_else(|_| std::collections::HashMap::from([("usd".to_string(), bitcoin_price)]));
 
                         app_data.set(AppData {
                             state: AppState::Ready {
                                 distribution,
                                 bitcoin_price,
                                 network_stats,
+                                exchange_rates,
                             },
                             ..(*app_data).clone()
                         });
+                        last_updated.set(Some(js_sys::Date::now()));
                     }
                     Err(e) => {
                         console::log_1(
@@ -154,6 +430,122 @@ pub fn app() -> Html {
         });
     }
 
+    // Auto-calculate once for a shared amount from the URL, as soon as the distribution is ready
+    {
+        let app_data = app_data.clone();
+        let percentile_calculator = percentile_calculator.clone();
+        let shared_amount = shared_amount.clone();
+        let state = app_data.state.clone();
+
+        use_effect_with(state, move |state| {
+            if let (Some(amount), AppState::Ready { distribution, .. }) = (&*shared_amount, state)
+            {
+                spawn_percentile_calculation(
+                    app_data.clone(),
+                    percentile_calculator.clone(),
+                    distribution.clone(),
+                    amount.clone(),
+                );
+                shared_amount.set(None);
+            }
+            || ()
+        });
+    }
+
+    // Keep the address bar's `?amount=` in sync with the latest result, so the page can be
+    // bookmarked or reloaded back to the same computed position
+    {
+        let calculation_result = app_data.calculation_result.clone();
+        use_effect_with(calculation_result, move |calculation_result| {
+            if let Some(result) = calculation_result {
+                sync_deep_link_url(result.user_bitcoin_amount);
+            }
+            || ()
+        });
+    }
+
+    // Live-poll the Bitcoin price (and, every tenth poll, the full distribution) so the welcome
+    // tile and any open result stay current without a manual refresh. Stops polling once the
+    // component unmounts, via the `cancelled` flag set in the effect's cleanup.
+    {
+        let app_data = app_data.clone();
+        let api_service = api_service.clone();
+        let last_updated = last_updated.clone();
+
+        use_effect_with((), move |_| {
+            let cancelled = std::rc::Rc::new(std::cell::Cell::new(false));
+            let cancelled_for_task = cancelled.clone();
+
+            spawn_local(async move {
+                let mut poll_count: u32 = 0;
+
+                loop {
+                    TimeoutFuture::new(PRICE_POLL_INTERVAL_MS).await;
+                    if cancelled_for_task.get() {
+                        break;
+                    }
+
+                    let (distribution, network_stats, exchange_rates) = match &app_data.state {
+                        AppState::Ready { distribution, network_stats, exchange_rates, .. } => {
+                            (distribution.clone(), network_stats.clone(), exchange_rates.clone())
+                        }
+                        _ => continue,
+                    };
+
+                    let bitcoin_price = match api_service.fetch_bitcoin_price().await {
+                        Ok(price) => price,
+                        Err(e) => {
+                            console::log_1(&format!("❌ Live price poll failed: {}", e).into());
+                            continue;
+                        }
+                    };
+
+                    poll_count += 1;
+                    let distribution = if poll_count % DISTRIBUTION_POLL_EVERY_N_POLLS == 0 {
+                        api_service.fetch_bitcoin_distribution().await.unwrap_or(distribution)
+                    } else {
+                        distribution
+                    };
+
+                    app_data.set(AppData {
+                        state: AppState::Ready {
+                            distribution,
+                            bitcoin_price,
+                            network_stats,
+                            exchange_rates,
+                        },
+                        ..(*app_data).clone()
+                    });
+                    last_updated.set(Some(js_sys::Date::now()));
+                }
+            });
+
+            move || cancelled.set(true)
+        });
+    }
+
+    // Tick once a second purely to re-render the "last updated N seconds ago" text between polls
+    {
+        let staleness_tick = staleness_tick.clone();
+
+        use_effect_with((), move |_| {
+            let cancelled = std::rc::Rc::new(std::cell::Cell::new(false));
+            let cancelled_for_task = cancelled.clone();
+
+            spawn_local(async move {
+                loop {
+                    TimeoutFuture::new(1_000).await;
+                    if cancelled_for_task.get() {
+                        break;
+                    }
+                    staleness_tick.set((*staleness_tick).wrapping_add(1));
+                }
+            });
+
+            move || cancelled.set(true)
+        });
+    }
+
     // Handle user input for Bitcoin amount
     let on_input_change = {
         let app_data_clone = app_data.clone();
@@ -194,6 +586,105 @@ pub fn app() -> Html {
         })
     };
 
+    // Clear the stored preference and go back to following the OS color scheme, re-enabling
+    // `watch_system_theme`'s live updates (it otherwise ignores OS changes once a preference
+    // has been explicitly saved via `on_theme_change` above)
+    let on_reset_to_system = {
+        let current_theme = current_theme.clone();
+        Callback::from(move |_: ()| {
+            if let Err(e) = ThemeManager::clear_stored_theme() {
+                console::log_1(&format!("❌ Failed to clear stored theme: {:?}", e).into());
+            }
+
+            let system_theme = ThemeManager::get_system_theme();
+            current_theme.set(system_theme);
+
+            if let Err(e) = ThemeManager::apply_theme_with_retry(system_theme) {
+                console::log_1(&format!("❌ Failed to apply system theme: {:?}", e).into());
+            } else {
+                console::log_1(&"✅ Reverted to system theme".into());
+            }
+        })
+    };
+
+    // Handle chart palette changes
+    let on_palette_change = {
+        let current_palette = current_palette.clone();
+        Callback::from(move |new_palette: ChartPalette| {
+            console::log_1(&format!("🎨 Changing chart palette to: {:?}", new_palette).into());
+
+            current_palette.set(new_palette);
+
+            let config = ChartThemeConfig {
+                extends: Some(new_palette.as_str().to_string()),
+                ..ChartThemeConfig::default()
+            };
+
+            if let Err(e) = ThemeManager::save_chart_theme_config(&config) {
+                console::log_1(&format!("❌ Failed to save chart palette: {:?}", e).into());
+            } else {
+                console::log_1(&"✅ Chart palette saved successfully".into());
+            }
+        })
+    };
+
+    // Handle a user-triggered refresh (pull-to-refresh gesture or the desktop button): re-fetch
+    // live data in place, keeping the stale dashboard on screen instead of dropping back to
+    // AppState::Loading, and ignore re-triggers while one is already in flight.
+    let on_refresh = {
+        let app_data = app_data.clone();
+        let api_service = api_service.clone();
+        let refreshing = refreshing.clone();
+        let last_updated = last_updated.clone();
+
+        Callback::from(move |_: ()| {
+            if *refreshing {
+                return;
+            }
+
+            refreshing.set(true);
+            console::log_1(&"🔄 Refreshing Bitcoin distribution data...".into());
+
+            let app_data = app_data.clone();
+            let api_service = api_service.clone();
+            let refreshing = refreshing.clone();
+            let last_updated = last_updated.clone();
+
+            spawn_local(async move {
+                match api_service.fetch_bitcoin_distribution().await {
+                    Ok(distribution) => {
+                        console::log_1(
+                            &format!("✅ Refreshed distribution data: {} ranges", distribution.ranges.len()).into(),
+                        );
+
+                        let bitcoin_price = api_service.fetch_bitcoin_price().await.unwrap_or(50000.0);
+                        let network_stats = api_service.fetch_network_stats().await.unwrap_or_default();
+                        let exchange_rates = api_service
+                            .fetch_exchange_rates()
+                            .await
+                            .unwrap_or_else(|_| std::collections::HashMap::from([("usd".to_string(), bitcoin_price)]));
+
+                        app_data.set(AppData {
+                            state: AppState::Ready {
+                                distribution,
+                                bitcoin_price,
+                                network_stats,
+                                exchange_rates,
+                            },
+                            ..(*app_data).clone()
+                        });
+                        last_updated.set(Some(js_sys::Date::now()));
+                    }
+                    Err(e) => {
+                        console::log_1(&format!("❌ Refresh failed: {}", e).into());
+                    }
+                }
+
+                refreshing.set(false);
+            });
+        })
+    };
+
     // Helper function to create preset button handlers
     let create_preset_handler = {
         let app_data_for_presets = app_data.clone();
@@ -222,77 +713,218 @@ pub fn app() -> Html {
 
             if let AppState::Ready { distribution, .. } = &app_data.state {
                 let distribution = distribution.clone();
+                spawn_percentile_calculation(app_data, percentile_calculator, distribution, user_input);
+            }
+        })
+    };
+
+    // Toggle between manual BTC-amount entry and looking holdings up by address
+    let on_toggle_address_lookup = {
+        let address_lookup_mode = address_lookup_mode.clone();
+        let address_lookup_error = address_lookup_error.clone();
+        Callback::from(move |_: MouseEvent| {
+            address_lookup_mode.set(!*address_lookup_mode);
+            address_lookup_error.set(None);
+        })
+    };
+
+    let on_address_input_change = {
+        let address_input = address_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+            address_input.set(input.value());
+        })
+    };
+
+    // Look up a pasted address's balance and auto-run the percentile calculation for it
+    let on_lookup_address = {
+        let app_data = app_data.clone();
+        let percentile_calculator = percentile_calculator.clone();
+        let api_service = api_service.clone();
+        let address_input = address_input.clone();
+        let address_lookup_error = address_lookup_error.clone();
+        let is_looking_up_address = is_looking_up_address.clone();
 
-                spawn_local(async move {
-                    // Set calculating state
+        Callback::from(move |_: MouseEvent| {
+            let app_data = app_data.clone();
+            let percentile_calculator = percentile_calculator.clone();
+            let api_service = api_service.clone();
+            let address = (*address_input).trim().to_string();
+            let address_lookup_error = address_lookup_error.clone();
+            let is_looking_up_address = is_looking_up_address.clone();
+
+            if let Err(e) = crate::utils::validators::validate_bitcoin_address(&address) {
+                address_lookup_error.set(Some(e));
+                return;
+            }
+
+            let distribution = match &app_data.state {
+                AppState::Ready { distribution, .. } => distribution.clone(),
+                _ => return,
+            };
+
+            address_lookup_error.set(None);
+            is_looking_up_address.set(true);
+
+            spawn_local(async move {
+                match api_service.fetch_address_balance(&address).await {
+                    Ok(balance) => {
+                        is_looking_up_address.set(false);
+                        spawn_percentile_calculation(
+                            app_data,
+                            percentile_calculator,
+                            distribution,
+                            format!("{:.8}", balance),
+                        );
+                    }
+                    Err(e) => {
+                        is_looking_up_address.set(false);
+                        console::log_1(&format!("❌ Address lookup failed: {}", e).into());
+                        address_lookup_error.set(Some(e));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_portfolio_label_change = {
+        let portfolio_label_input = portfolio_label_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+            portfolio_label_input.set(input.value());
+        })
+    };
+
+    let on_portfolio_amount_change = {
+        let portfolio_amount_input = portfolio_amount_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input = e.target_unchecked_into::<web_sys::HtmlInputElement>();
+            portfolio_amount_input.set(input.value());
+        })
+    };
+
+    // Persist `entries` and clear any stale contribution breakdown, since it no longer matches
+    // the portfolio it was computed from
+    fn save_portfolio(app_data: &UseStateHandle<AppData>, entries: Vec<PortfolioEntry>) {
+        if let Err(e) = PortfolioStorage::save(&entries) {
+            console::log_1(&format!("❌ Failed to save portfolio: {:?}", e).into());
+        }
+        app_data.set(AppData {
+            portfolio_entries: entries,
+            portfolio_contribution_shares: None,
+            ..(**app_data).clone()
+        });
+    }
+
+    // Add a labeled holding to the portfolio tracker
+    let on_add_portfolio_entry = {
+        let app_data = app_data.clone();
+        let portfolio_label_input = portfolio_label_input.clone();
+        let portfolio_amount_input = portfolio_amount_input.clone();
+        let portfolio_error = portfolio_error.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let label = (*portfolio_label_input).trim().to_string();
+            let amount_str = (*portfolio_amount_input).trim().to_string();
+
+            if label.is_empty() {
+                portfolio_error.set(Some("Give this holding a label".to_string()));
+                return;
+            }
+
+            match amount_str.parse::<f64>() {
+                Ok(btc_amount) if btc_amount > 0.0 => {
+                    portfolio_error.set(None);
+                    let mut entries = app_data.portfolio_entries.clone();
+                    entries.push(PortfolioEntry { label, btc_amount });
+                    save_portfolio(&app_data, entries);
+                    portfolio_label_input.set(String::new());
+                    portfolio_amount_input.set(String::new());
+                }
+                _ => portfolio_error.set(Some("Enter a positive BTC amount".to_string())),
+            }
+        })
+    };
+
+    // Build a click handler that removes the portfolio entry at `index`, mirroring
+    // `create_preset_handler`'s per-button closure factory above
+    let create_remove_portfolio_handler = {
+        let app_data_for_removal = app_data.clone();
+        move |index: usize| {
+            let app_data_clone = app_data_for_removal.clone();
+            Callback::from(move |_: MouseEvent| {
+                let mut entries = app_data_clone.portfolio_entries.clone();
+                if index < entries.len() {
+                    entries.remove(index);
+                    save_portfolio(&app_data_clone, entries);
+                }
+            })
+        }
+    };
+
+    // Combine the portfolio's entries into one aggregate percentile calculation, feeding the
+    // combined result into the same `calculation_result` every chart already renders from
+    let on_calculate_portfolio = {
+        let app_data = app_data.clone();
+        let percentile_calculator = percentile_calculator.clone();
+        let portfolio_error = portfolio_error.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let distribution = match &app_data.state {
+                AppState::Ready { distribution, .. } => distribution.clone(),
+                _ => return,
+            };
+
+            let amounts: Vec<f64> = app_data
+                .portfolio_entries
+                .iter()
+                .map(|entry| entry.btc_amount)
+                .collect();
+
+            match percentile_calculator.aggregate_portfolio_percentile(&amounts, &distribution) {
+                Ok(portfolio_result) => {
+                    portfolio_error.set(None);
                     app_data.set(AppData {
-                        is_calculating: true,
+                        calculation_result: Some(portfolio_result.combined),
+                        portfolio_contribution_shares: Some(portfolio_result.contribution_shares),
                         ..(*app_data).clone()
                     });
+                }
+                Err(e) => portfolio_error.set(Some(e)),
+            }
+        })
+    };
 
-                    // Parse user input
-                    match user_input.parse::<f64>() {
-                        Ok(amount) => {
-                            console::log_1(
-                                &format!("🧮 Calculating percentile for {} BTC", amount).into(),
-                            );
-
-                            // Add small delay for UX
-                            TimeoutFuture::new(300).await;
-
-                            // Calculate percentile and additional analysis
-                            match percentile_calculator
-                                .calculate_user_percentile(amount, &distribution)
-                            {
-                                Ok(result) => {
-                                    console::log_1(
-                                        &format!(
-                                            "✅ Calculation complete: {:.2}%",
-                                            result.percentile
-                                        )
-                                        .into(),
-                                    );
-
-                                    // Calculate wealth concentration analysis
-                                    let wealth_analysis = percentile_calculator
-                                        .calculate_wealth_concentration(&distribution)
-                                        .unwrap_or_default();
-
-                                    // Calculate percentile thresholds
-                                    let percentile_thresholds = percentile_calculator
-                                        .calculate_percentile_thresholds(&distribution)
-                                        .unwrap_or_default();
-
-                                    app_data.set(AppData {
-                                        calculation_result: Some(result),
-                                        wealth_analysis: Some(wealth_analysis),
-                                        percentile_thresholds: Some(percentile_thresholds),
-                                        is_calculating: false,
-                                        ..(*app_data).clone()
-                                    });
-                                }
-                                Err(e) => {
-                                    console::log_1(&format!("❌ Calculation failed: {}", e).into());
-                                    app_data.set(AppData {
-                                        is_calculating: false,
-                                        ..(*app_data).clone()
-                                    });
-                                }
-                            }
-                        }
-                        Err(_) => {
-                            console::log_1(&"❌ Invalid Bitcoin amount entered".into());
-                            app_data.set(AppData {
-                                is_calculating: false,
-                                ..(*app_data).clone()
-                            });
+    // Export the full wealth distribution as CSV, so users can take it into a spreadsheet
+    let on_export_distribution_csv = {
+        let app_data = app_data.clone();
+        Callback::from(move |_| {
+            if let AppState::Ready { distribution, .. } = &app_data.state {
+                match crate::utils::csv_export::distribution_to_csv(distribution) {
+                    Ok(csv) => {
+                        if let Err(e) = trigger_text_download(&csv, "bitcoin-distribution.csv", "text/csv") {
+                            console::log_1(&format!("Failed to download distribution CSV: {:?}", e).into());
                         }
                     }
-                });
+                    Err(e) => console::log_1(&format!("Failed to build distribution CSV: {}", e).into()),
+                }
             }
         })
     };
 
+    // Flip the whole page's fiat display between currencies; BTC itself stays canonical
+    let on_currency_change = {
+        let app_data = app_data.clone();
+        Callback::from(move |currency: Currency| {
+            app_data.set(AppData {
+                selected_currency: currency,
+                ..(*app_data).clone()
+            });
+        })
+    };
+
+    let selected_currency = app_data.selected_currency;
+
     let render_content = match &app_data.state {
         AppState::Loading => {
             html! {
@@ -305,12 +937,41 @@ pub fn app() -> Html {
             distribution,
             bitcoin_price,
             network_stats: _,
+            exchange_rates,
         } => {
+            let currency_rate = exchange_rates
+                .get(selected_currency.code())
+                .copied()
+                .unwrap_or(*bitcoin_price);
+
+            // Re-evaluated every `staleness_tick` so the elapsed time keeps counting up between
+            // live-price polls, not just when a poll actually lands
+            let _ = *staleness_tick;
+            let staleness_label = last_updated.map(|updated_at_ms| {
+                let elapsed_secs = ((js_sys::Date::now() - updated_at_ms) / 1000.0).max(0.0) as u64;
+                if elapsed_secs < 5 {
+                    "Updated just now".to_string()
+                } else {
+                    format!("Updated {}s ago", elapsed_secs)
+                }
+            });
+
             html! {
                 <div class="space-y-8">
                     // Welcome Section
                     <div class="bg-gradient-to-r from-blue-600 to-purple-600 rounded-lg p-8 text-white">
-                        <h2 class="text-3xl font-bold mb-4">{"Welcome to Bitcoin Wealth Comparison"}</h2>
+                        <div class="flex items-center justify-between mb-4">
+                            <h2 class="text-3xl font-bold">{"Welcome to Bitcoin Wealth Comparison"}</h2>
+                            <div class="flex items-center gap-3">
+                                if let Some(label) = &staleness_label {
+                                    <span class="text-xs opacity-75">{label}</span>
+                                }
+                                <CurrencySelector
+                                    current_currency={selected_currency}
+                                    on_currency_change={on_currency_change.clone()}
+                                />
+                            </div>
+                        </div>
                         <p class="text-xl mb-6">
                             {"Discover where your Bitcoin holdings rank among global addresses. All calculations are performed locally in your browser."}
                         </p>
@@ -324,10 +985,18 @@ pub fn app() -> Html {
                                  <div class="text-sm opacity-90">{"Total Supply (BTC)"}</div>
                              </div>
                              <div class="bg-white/20 rounded-lg p-4">
-                                 <div class="text-2xl font-bold">{format!("${:.0}", bitcoin_price)}</div>
+                                 <div class="text-2xl font-bold">{format_currency_amount(1.0, selected_currency, currency_rate)}</div>
                                  <div class="text-sm opacity-90">{"Bitcoin Price"}</div>
                              </div>
                         </div>
+                        <div class="mt-4 text-right">
+                            <button
+                                onclick={on_export_distribution_csv}
+                                class="px-3 py-1 text-sm bg-white/20 hover:bg-white/30 rounded-full transition-colors duration-200"
+                            >
+                                {"⬇ Export Distribution CSV"}
+                            </button>
+                        </div>
                     </div>
 
                     // Enhanced Input Section with Smart Suggestions
@@ -383,59 +1052,197 @@ pub fn app() -> Html {
                         // Main Input with Enhanced UX
                         <div class="space-y-4">
                             <div>
-                                <label class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">{"Your Bitcoin Amount"}</label>
-                                <div class="relative">
-                                    <input
-                                        type="number"
-                                        step="0.00000001"
-                                        min="0"
-                                        max="21000000"
-                                        placeholder="0.00000000"
-                                        value={app_data.user_input.clone()}
-                                        oninput={on_input_change}
-                                        onkeypress={{
-                                            let on_calculate_clone = on_calculate.clone();
-                                            let app_data_clone = app_data.clone();
-                                            Callback::from(move |e: KeyboardEvent| {
-                                                if e.key() == "Enter" && !app_data_clone.user_input.is_empty() && !app_data_clone.is_calculating {
-                                                    e.prevent_default();
-                                                    on_calculate_clone.emit(MouseEvent::new("click").unwrap());
-                                                }
-                                            })
-                                        }}
-                                        class="w-full p-4 pr-16 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent font-mono text-lg transition-all duration-200 hover:border-orange-300 dark:hover:border-orange-600"
-                                    />
-                                    <div class="absolute inset-y-0 right-0 flex items-center pr-4 pointer-events-none">
-                                        <span class="text-gray-500 dark:text-gray-400 font-semibold">{"BTC"}</span>
-                                    </div>
+                                <div class="flex items-center justify-between mb-2">
+                                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-300">
+                                        if *address_lookup_mode { {"Your Bitcoin Address"} } else { {"Your Bitcoin Amount"} }
+                                    </label>
+                                    <button
+                                        onclick={on_toggle_address_lookup}
+                                        class="text-sm text-orange-600 dark:text-orange-400 hover:underline"
+                                    >
+                                        if *address_lookup_mode { {"Enter an amount instead"} } else { {"Look up by address instead"} }
+                                    </button>
                                 </div>
-                                if !app_data.user_input.is_empty() {
-                                    if let Ok(amount) = app_data.user_input.parse::<f64>() {
-                                        <div class="mt-2 text-sm text-gray-600 dark:text-gray-400">
-                                            {format!("≈ {} satoshis", format_number_with_commas(amount * 100_000_000.0))}
+                                if *address_lookup_mode {
+                                    <div class="relative">
+                                        <input
+                                            type="text"
+                                            placeholder="bc1q..."
+                                            value={(*address_input).clone()}
+                                            oninput={on_address_input_change}
+                                            onkeypress={{
+                                                let on_lookup_address_clone = on_lookup_address.clone();
+                                                let address_input = address_input.clone();
+                                                let is_looking_up_address = is_looking_up_address.clone();
+                                                Callback::from(move |e: KeyboardEvent| {
+                                                    if e.key() == "Enter" && !address_input.is_empty() && !*is_looking_up_address {
+                                                        e.prevent_default();
+                                                        on_lookup_address_clone.emit(MouseEvent::new("click").unwrap());
+                                                    }
+                                                })
+                                            }}
+                                            class="w-full p-4 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent font-mono text-lg transition-all duration-200 hover:border-orange-300 dark:hover:border-orange-600"
+                                        />
+                                    </div>
+                                    if let Some(error) = &*address_lookup_error {
+                                        <div class="mt-2 text-sm text-red-600 dark:text-red-400">
+                                            {error}
                                         </div>
                                     }
+                                } else {
+                                    <div class="relative">
+                                        <input
+                                            type="number"
+                                            step="0.00000001"
+                                            min="0"
+                                            max="21000000"
+                                            placeholder="0.00000000"
+                                            value={app_data.user_input.clone()}
+                                            oninput={on_input_change}
+                                            onkeypress={{
+                                                let on_calculate_clone = on_calculate.clone();
+                                                let app_data_clone = app_data.clone();
+                                                Callback::from(move |e: KeyboardEvent| {
+                                                    if e.key() == "Enter" && !app_data_clone.user_input.is_empty() && !app_data_clone.is_calculating {
+                                                        e.prevent_default();
+                                                        on_calculate_clone.emit(MouseEvent::new("click").unwrap());
+                                                    }
+                                                })
+                                            }}
+                                            class="w-full p-4 pr-16 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent font-mono text-lg transition-all duration-200 hover:border-orange-300 dark:hover:border-orange-600"
+                                        />
+                                        <div class="absolute inset-y-0 right-0 flex items-center pr-4 pointer-events-none">
+                                            <span class="text-gray-500 dark:text-gray-400 font-semibold">{"BTC"}</span>
+                                        </div>
+                                    </div>
+                                    if !app_data.user_input.is_empty() {
+                                        if let Ok(amount) = app_data.user_input.parse::<f64>() {
+                                            <div class="mt-2 text-sm text-gray-600 dark:text-gray-400">
+                                                {format!("≈ {} satoshis", format_number_with_commas(amount * 100_000_000.0))}
+                                            </div>
+                                        }
+                                    }
                                 }
                             </div>
-                            
+
+                            if *address_lookup_mode {
+                                <button
+                                    onclick={on_lookup_address}
+                                    disabled={address_input.is_empty() || *is_looking_up_address}
+                                    class="w-full px-6 py-4 bg-gradient-to-r from-orange-500 to-orange-600 hover:from-orange-600 hover:to-orange-700 disabled:opacity-50 disabled:cursor-not-allowed text-white rounded-lg font-semibold text-lg transition-all duration-300 transform hover:scale-105 hover:shadow-lg disabled:hover:scale-100 disabled:hover:shadow-none"
+                                >
+                                    if *is_looking_up_address {
+                                        <div class="flex items-center justify-center">
+                                            <svg class="animate-spin -ml-1 mr-3 h-5 w-5 text-white" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24">
+                                                <circle class="opacity-25" cx="12" cy="12" r="10" stroke="currentColor" stroke-width="4"></circle>
+                                                <path class="opacity-75" fill="currentColor" d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"></path>
+                                            </svg>
+                                            {"Looking Up Address..."}
+                                        </div>
+                                    } else {
+                                        {"🔎 Look Up My Holdings"}
+                                    }
+                                </button>
+                            } else {
+                                <button
+                                    onclick={on_calculate}
+                                    disabled={app_data.user_input.is_empty() || app_data.is_calculating}
+                                    class="w-full px-6 py-4 bg-gradient-to-r from-orange-500 to-orange-600 hover:from-orange-600 hover:to-orange-700 disabled:opacity-50 disabled:cursor-not-allowed text-white rounded-lg font-semibold text-lg transition-all duration-300 transform hover:scale-105 hover:shadow-lg disabled:hover:scale-100 disabled:hover:shadow-none"
+                                >
+                                    if app_data.is_calculating {
+                                        <div class="flex items-center justify-center">
+                                            <svg class="animate-spin -ml-1 mr-3 h-5 w-5 text-white" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24">
+                                                <circle class="opacity-25" cx="12" cy="12" r="10" stroke="currentColor" stroke-width="4"></circle>
+                                                <path class="opacity-75" fill="currentColor" d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"></path>
+                                            </svg>
+                                            {"Calculating Your Rank..."}
+                                        </div>
+                                    } else {
+                                        {"🚀 Calculate My Bitcoin Rank"}
+                                    }
+                                </button>
+                            }
+                        </div>
+                    </div>
+
+                    // Portfolio Tracker: combine multiple labeled holdings into one aggregate rank
+                    <div class="bg-white dark:bg-gray-800 rounded-lg shadow-lg p-6 hover:shadow-xl transition-shadow duration-300">
+                        <div class="mb-6">
+                            <h3 class="text-2xl font-bold mb-2 text-gray-900 dark:text-white">{"Portfolio Tracker"}</h3>
+                            <p class="text-gray-600 dark:text-gray-300">{"Add labeled holdings (e.g. \"cold storage\", \"exchange\") and rank them together"}</p>
+                        </div>
+
+                        if !app_data.portfolio_entries.is_empty() {
+                            <table class="w-full mb-4 text-sm">
+                                <thead>
+                                    <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-200 dark:border-gray-700">
+                                        <th class="py-2">{"Label"}</th>
+                                        <th class="py-2">{"BTC"}</th>
+                                        if app_data.portfolio_contribution_shares.is_some() {
+                                            <th class="py-2">{"Share"}</th>
+                                        }
+                                        <th class="py-2"></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for app_data.portfolio_entries.iter().enumerate().map(|(index, entry)| html! {
+                                        <tr class="border-b border-gray-100 dark:border-gray-700/50">
+                                            <td class="py-2 text-gray-900 dark:text-white">{&entry.label}</td>
+                                            <td class="py-2 font-mono text-gray-900 dark:text-white">{format!("{:.8}", entry.btc_amount)}</td>
+                                            if let Some(shares) = &app_data.portfolio_contribution_shares {
+                                                <td class="py-2 text-gray-600 dark:text-gray-300">
+                                                    {shares.get(index).map(|share| format!("{:.1}%", *share * 100.0)).unwrap_or_default()}
+                                                </td>
+                                            }
+                                            <td class="py-2 text-right">
+                                                <button
+                                                    onclick={create_remove_portfolio_handler(index)}
+                                                    class="text-red-500 hover:text-red-700 dark:hover:text-red-400"
+                                                >
+                                                    {"✕"}
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }) }
+                                </tbody>
+                            </table>
+                        }
+
+                        <div class="flex flex-col md:flex-row gap-2 mb-2">
+                            <input
+                                type="text"
+                                placeholder="Label (e.g. cold storage)"
+                                value={(*portfolio_label_input).clone()}
+                                oninput={on_portfolio_label_change}
+                                class="flex-1 p-3 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent"
+                            />
+                            <input
+                                type="number"
+                                step="0.00000001"
+                                min="0"
+                                placeholder="BTC amount"
+                                value={(*portfolio_amount_input).clone()}
+                                oninput={on_portfolio_amount_change}
+                                class="flex-1 p-3 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg focus:ring-2 focus:ring-orange-500 focus:border-transparent font-mono"
+                            />
                             <button
-                                onclick={on_calculate}
-                                disabled={app_data.user_input.is_empty() || app_data.is_calculating}
-                                class="w-full px-6 py-4 bg-gradient-to-r from-orange-500 to-orange-600 hover:from-orange-600 hover:to-orange-700 disabled:opacity-50 disabled:cursor-not-allowed text-white rounded-lg font-semibold text-lg transition-all duration-300 transform hover:scale-105 hover:shadow-lg disabled:hover:scale-100 disabled:hover:shadow-none"
+                                onclick={on_add_portfolio_entry}
+                                class="px-4 py-3 bg-gray-100 dark:bg-gray-700 hover:bg-orange-100 dark:hover:bg-orange-900 text-gray-700 dark:text-gray-300 rounded-lg transition-colors duration-200 hover:text-orange-600 dark:hover:text-orange-400"
                             >
-                                if app_data.is_calculating {
-                                    <div class="flex items-center justify-center">
-                                        <svg class="animate-spin -ml-1 mr-3 h-5 w-5 text-white" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24">
-                                            <circle class="opacity-25" cx="12" cy="12" r="10" stroke="currentColor" stroke-width="4"></circle>
-                                            <path class="opacity-75" fill="currentColor" d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"></path>
-                                        </svg>
-                                        {"Calculating Your Rank..."}
-                                    </div>
-                                } else {
-                                    {"🚀 Calculate My Bitcoin Rank"}
-                                }
+                                {"+ Add Holding"}
                             </button>
                         </div>
+                        if let Some(error) = &*portfolio_error {
+                            <div class="text-sm text-red-600 dark:text-red-400 mb-2">{error}</div>
+                        }
+
+                        <button
+                            onclick={on_calculate_portfolio}
+                            disabled={app_data.portfolio_entries.is_empty()}
+                            class="w-full px-6 py-3 bg-gradient-to-r from-orange-500 to-orange-600 hover:from-orange-600 hover:to-orange-700 disabled:opacity-50 disabled:cursor-not-allowed text-white rounded-lg font-semibold transition-all duration-300"
+                        >
+                            {"📊 Calculate Portfolio Percentile"}
+                        </button>
                     </div>
 
                     // Enhanced Results Section with Better UX
@@ -446,8 +1253,28 @@ pub fn app() -> Html {
                                 <div class="text-4xl mb-2">{"🎉"}</div>
                                 <h3 class="text-3xl font-bold mb-2 text-gray-900 dark:text-white">{"Your Bitcoin Rank"}</h3>
                                 <p class="text-lg text-gray-600 dark:text-gray-300">{"Here's how you compare to Bitcoin holders worldwide"}</p>
+                                <div class="flex items-center justify-center gap-2 mt-3">
+                                    <ExportMenu
+                                        result={Some(result.clone())}
+                                        wealth_analysis={app_data.wealth_analysis.clone()}
+                                        distribution={Some(distribution.clone())}
+                                    />
+                                    <CopyToClipboard
+                                        text={build_result_summary(result, *bitcoin_price)}
+                                        label={"📋 Copy My Result".to_string()}
+                                        class={"px-3 py-1 text-sm bg-gray-100 dark:bg-gray-700 hover:bg-orange-100 dark:hover:bg-orange-900 text-gray-700 dark:text-gray-300 rounded-full transition-colors duration-200 hover:text-orange-600 dark:hover:text-orange-400".to_string()}
+                                    />
+                                </div>
+                                if let Some(share_url) = build_deep_link_url(result.user_bitcoin_amount) {
+                                    <div class="flex flex-col items-center mt-4">
+                                        <div class="bg-white p-2 rounded-lg inline-block">
+                                            <QrCode data={share_url.clone()} size={140} />
+                                        </div>
+                                        <p class="text-xs text-gray-500 dark:text-gray-400 mt-1 break-all max-w-xs">{share_url}</p>
+                                    </div>
+                                }
                             </div>
-                            
+
                             // Main Stats Grid with Enhanced Design
                             <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-6 mb-8">
                                 <div class="bg-gradient-to-br from-blue-500 to-blue-600 rounded-xl p-6 text-white transform hover:scale-105 transition-transform duration-300 shadow-lg">
@@ -483,7 +1310,9 @@ pub fn app() -> Html {
                                         <div class="text-xs opacity-75 bg-white/20 px-2 py-1 rounded-full">{"HOLDINGS"}</div>
                                     </div>
                                     <div class="text-2xl font-bold mb-1">{format!("{:.4}", result.user_bitcoin_amount)}</div>
-                                    <div class="text-sm opacity-90">{"Bitcoin Amount"}</div>
+                                    <div class="text-sm opacity-90">
+                                        {format!("≈ {}", format_currency_amount(result.user_bitcoin_amount, selected_currency, currency_rate))}
+                                    </div>
                                 </div>
                             </div>
 
@@ -537,6 +1366,14 @@ pub fn app() -> Html {
                             </div>
                         </div>
 
+                        // Percentile Trend: real history recorded for this amount, if any has
+                        // been recorded yet; TrendlineChart falls back to a flat placeholder
+                        // itself when the history is empty
+                        <TrendlineChart
+                            history={PercentileHistoryStorage::history_for(result.user_bitcoin_amount).unwrap_or_default()}
+                            current_percentile={Some(result.percentile)}
+                        />
+
                         // Wealth Analysis Section (if available)
                         if let Some(wealth_analysis) = &app_data.wealth_analysis {
                             <div class="bg-white dark:bg-gray-800 rounded-xl shadow-lg p-6 border border-gray-200 dark:border-gray-700">
@@ -573,6 +1410,27 @@ pub fn app() -> Html {
                                             <div class="text-xs text-emerald-600 dark:text-emerald-400">{"Market concentration index"}</div>
                                         </div>
                                     }
+                                    if let Some(palma) = wealth_analysis.get("palma_ratio") {
+                                        <div class="bg-gradient-to-br from-purple-50 to-purple-100 dark:from-purple-900/20 dark:to-purple-800/20 rounded-lg p-4 border border-purple-200 dark:border-purple-700">
+                                            <div class="text-sm font-medium text-purple-600 dark:text-purple-400 mb-1">{"Palma Ratio"}</div>
+                                            <div class="text-2xl font-bold text-purple-900 dark:text-purple-100">{format!("{:.2}", palma)}</div>
+                                            <div class="text-xs text-purple-600 dark:text-purple-400">{"Top 10% share ÷ bottom 40% share"}</div>
+                                        </div>
+                                    }
+                                    if let Some(theil) = wealth_analysis.get("theil_index") {
+                                        <div class="bg-gradient-to-br from-sky-50 to-sky-100 dark:from-sky-900/20 dark:to-sky-800/20 rounded-lg p-4 border border-sky-200 dark:border-sky-700">
+                                            <div class="text-sm font-medium text-sky-600 dark:text-sky-400 mb-1">{"Theil Index"}</div>
+                                            <div class="text-2xl font-bold text-sky-900 dark:text-sky-100">{format!("{:.3}", theil)}</div>
+                                            <div class="text-xs text-sky-600 dark:text-sky-400">{"Entropy-based inequality (0=equal)"}</div>
+                                        </div>
+                                    }
+                                    if let Some(atkinson) = wealth_analysis.get("atkinson_index") {
+                                        <div class="bg-gradient-to-br from-rose-50 to-rose-100 dark:from-rose-900/20 dark:to-rose-800/20 rounded-lg p-4 border border-rose-200 dark:border-rose-700">
+                                            <div class="text-sm font-medium text-rose-600 dark:text-rose-400 mb-1">{"Atkinson Index"}</div>
+                                            <div class="text-2xl font-bold text-rose-900 dark:text-rose-100">{format!("{:.3}", atkinson)}</div>
+                                            <div class="text-xs text-rose-600 dark:text-rose-400">{"Welfare loss from inequality (ε=0.5)"}</div>
+                                        </div>
+                                    }
                                 </div>
                             </div>
                         }
@@ -637,6 +1495,7 @@ pub fn app() -> Html {
                                         } else {
                                             format!("{:.8} BTC", amount)
                                         };
+                                        let fiat_text = format_currency_amount(*amount, selected_currency, currency_rate);
 
                                         html! {
                                             <div class={format!("bg-gradient-to-br {} rounded-xl p-5 border-2 {} transform hover:scale-105 transition-all duration-300 shadow-lg hover:shadow-xl", bg_color, border_color)}>
@@ -649,6 +1508,9 @@ pub fn app() -> Html {
                                                 <div class={format!("text-2xl font-black {} mb-2 tracking-tight", text_primary)}>
                                                     {amount_text}
                                                 </div>
+                                                <div class={format!("text-sm {} mb-1 opacity-90", text_secondary)}>
+                                                    {format!("≈ {}", fiat_text)}
+                                                </div>
                                                 <div class={format!("text-sm font-medium {} mb-1", text_secondary)}>
                                                     {format!("{:.1}th Percentile", percentile)}
                                                 </div>
@@ -681,6 +1543,24 @@ pub fn app() -> Html {
                         user_percentile={app_data.calculation_result.as_ref().map(|r| r.percentile)}
                     />
 
+                    // Lorenz curve and Gini coefficient (always visible when data is loaded)
+                    <LorenzChart
+                        distribution={distribution.clone()}
+                        user_percentile={app_data.calculation_result.as_ref().map(|r| r.percentile)}
+                    />
+
+                    // Wealth tier spread (always visible when data is loaded)
+                    <WealthBoxPlot
+                        distribution={distribution.clone()}
+                        user_percentile={app_data.calculation_result.as_ref().map(|r| r.percentile)}
+                    />
+
+                    // Shrimp-to-whale treemap (always visible when data is loaded)
+                    <WealthTreemap
+                        distribution={distribution.clone()}
+                        user_amount={app_data.calculation_result.as_ref().map(|r| r.user_bitcoin_amount)}
+                    />
+
                     // Network Statistics Section - Temporarily commented out due to syntax issues
                     // TODO: Fix the HTML macro syntax for the statistics section
                     /*
@@ -704,6 +1584,8 @@ pub fn app() -> Html {
                     <StatisticsChart
                         distribution={distribution.clone()}
                         bitcoin_price={*bitcoin_price}
+                        selected_currency={selected_currency}
+                        currency_rate={currency_rate}
                     />
 
                     // Distribution Overview
@@ -732,6 +1614,9 @@ pub fn app() -> Html {
                                                 {format!("{:.1}%", range.percentage_of_supply)}
                                             </div>
                                             <div class="text-sm text-gray-600 dark:text-gray-300">{"of supply"}</div>
+                                            <div class="text-xs text-gray-500 dark:text-gray-400">
+                                                {format!("≈ {}", format_currency_amount(range.total_btc, selected_currency, currency_rate))}
+                                            </div>
                                         </div>
                                     </div>
                                 }
@@ -765,10 +1650,15 @@ pub fn app() -> Html {
             <Header
                 current_theme={*current_theme}
                 on_theme_change={on_theme_change}
+                current_palette={*current_palette}
+                on_palette_change={on_palette_change}
+                on_reset_to_system={on_reset_to_system}
             />
             <main class="flex-1 container mx-auto px-4 py-8">
                 <div class="max-w-6xl mx-auto">
-                    {render_content}
+                    <RefreshControl on_refresh={on_refresh} refreshing={*refreshing}>
+                        {render_content}
+                    </RefreshControl>
                 </div>
             </main>
             <Footer />
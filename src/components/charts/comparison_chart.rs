@@ -1,14 +1,25 @@
 use crate::types::bitcoin::{BitcoinDistribution, PercentileResult};
 use crate::utils::chart_theme::{format_bitcoin_amount, format_percentile, MempoolChartTheme};
+use gloo_timers::future::TimeoutFuture;
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
-use web_sys::HtmlCanvasElement;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{window, HtmlAnchorElement, HtmlCanvasElement};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct ComparisonChartProps {
     pub user_result: PercentileResult,
     pub distribution: BitcoinDistribution,
+    /// Message shown instead of the chart when `distribution.ranges` has no data
+    #[prop_or_else(default_empty_text)]
+    pub empty_text: String,
+}
+
+fn default_empty_text() -> String {
+    "No distribution data available".to_string()
 }
 
 #[function_component(ComparisonChart)]
@@ -29,6 +40,59 @@ pub fn comparison_chart(props: &ComparisonChartProps) -> Html {
         });
     }
 
+    let on_export_svg = {
+        let user_result = props.user_result.clone();
+        let distribution = props.distribution.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Ok(svg) = render_comparison_chart_svg(&user_result, &distribution) {
+                let _ = trigger_blob_download(&svg, "comparison-chart.svg", "image/svg+xml");
+            }
+        })
+    };
+
+    let on_export_png = {
+        let canvas_ref = canvas_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                if let Ok(data_url) = canvas.to_data_url_with_type("image/png") {
+                    let _ = trigger_data_url_download(&data_url, "comparison-chart.png");
+                }
+            }
+        })
+    };
+
+    let copied = use_state(|| false);
+
+    let on_share = {
+        let user_result = props.user_result.clone();
+        let copied = copied.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut summary = build_share_summary(&user_result);
+            if let Some(url) = build_share_url(user_result.user_bitcoin_amount) {
+                summary.push(' ');
+                summary.push_str(&url);
+            }
+
+            let copied = copied.clone();
+            spawn_local(async move {
+                if copy_to_clipboard(&summary).await.is_ok() {
+                    copied.set(true);
+                    TimeoutFuture::new(2000).await;
+                    copied.set(false);
+                }
+            });
+        })
+    };
+
+    // Checked after every hook above has run, so the early return never disturbs hook order
+    if props.distribution.ranges.is_empty() {
+        return html! {
+            <div class="bg-white dark:bg-gray-800 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 flex items-center justify-center min-h-[300px]">
+                <p class="text-lg text-gray-500 dark:text-gray-400">{&props.empty_text}</p>
+            </div>
+        };
+    }
+
     html! {
         <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
             // Enhanced Header Section
@@ -44,14 +108,36 @@ pub fn comparison_chart(props: &ComparisonChartProps) -> Html {
 
             // Enhanced Chart Container
             <div class="relative bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
-                <div class="flex items-center mb-4">
-                    <div class="text-2xl mr-3">{"📈"}</div>
-                    <div>
-                        <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Percentile Distribution Curve"}</h4>
-                        <p class="text-sm text-gray-600 dark:text-gray-300">{"Your position on the global Bitcoin wealth distribution curve"}</p>
+                <div class="flex items-center justify-between mb-4">
+                    <div class="flex items-center">
+                        <div class="text-2xl mr-3">{"📈"}</div>
+                        <div>
+                            <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Percentile Distribution Curve"}</h4>
+                            <p class="text-sm text-gray-600 dark:text-gray-300">{"Your position on the global Bitcoin wealth distribution curve"}</p>
+                        </div>
+                    </div>
+                    <div class="flex gap-2">
+                        <button
+                            onclick={on_export_svg}
+                            class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                        >
+                            {"⬇ SVG"}
+                        </button>
+                        <button
+                            onclick={on_export_png}
+                            class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                        >
+                            {"⬇ PNG"}
+                        </button>
+                        <button
+                            onclick={on_share}
+                            class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                        >
+                            { if *copied { "✓ Copied!" } else { "🔗 Share" } }
+                        </button>
                     </div>
                 </div>
-                
+
                 <canvas
                     ref={canvas_ref}
                     width="800"
@@ -107,6 +193,10 @@ pub fn comparison_chart(props: &ComparisonChartProps) -> Html {
     }
 }
 
+/// Width/height the comparison chart is rendered at, shared by the canvas and SVG backends
+const CHART_WIDTH: u32 = 800;
+const CHART_HEIGHT: u32 = 300;
+
 fn draw_comparison_chart(
     canvas: HtmlCanvasElement,
     user_result: &PercentileResult,
@@ -114,9 +204,36 @@ fn draw_comparison_chart(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
     let root = backend.into_drawing_area();
+    render_comparison_chart(root, user_result, distribution)
+}
 
-    // Get mempool.space inspired theme
-    let theme = MempoolChartTheme::new();
+/// Render the comparison chart to an in-memory SVG string, for the "Export SVG" button
+fn render_comparison_chart_svg(
+    user_result: &PercentileResult,
+    distribution: &BitcoinDistribution,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut svg_content = String::new();
+    {
+        let backend = SVGBackend::with_string(&mut svg_content, (CHART_WIDTH, CHART_HEIGHT));
+        let root = backend.into_drawing_area();
+        render_comparison_chart(root, user_result, distribution)?;
+    }
+    Ok(svg_content)
+}
+
+/// Shared plotting logic for `draw_comparison_chart` and `render_comparison_chart_svg`, so the
+/// canvas and SVG backends never duplicate the chart-building body
+fn render_comparison_chart<DB>(
+    root: DrawingArea<DB, Shift>,
+    user_result: &PercentileResult,
+    distribution: &BitcoinDistribution,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    // Get the active theme, preferring a user-saved custom palette
+    let theme = MempoolChartTheme::current();
     root.fill(&theme.background)?;
 
     // Create a percentile-based visualization with more data points for smooth curve
@@ -300,3 +417,83 @@ fn get_wealth_category_ranges(theme: &MempoolChartTheme) -> Vec<(String, RGBColo
         ("Whale".to_string(), wealth_colors[5].1, 99.0, 100.0),
     ]
 }
+
+/// Name of the wealth category a percentile falls into, matching the spans used by
+/// `get_wealth_category_ranges`
+fn wealth_category_name(percentile: f64) -> &'static str {
+    match percentile {
+        p if p >= 99.0 => "Whale",
+        p if p >= 95.0 => "Shark",
+        p if p >= 90.0 => "Dolphin",
+        p if p >= 75.0 => "Fish",
+        p if p >= 50.0 => "Crab",
+        _ => "Shrimp",
+    }
+}
+
+/// Compact, shareable text summary of a user's result: percentile, BTC amount and wealth
+/// category, for the "Share my position" clipboard feature
+fn build_share_summary(user_result: &PercentileResult) -> String {
+    format!(
+        "I'm in the {} percentile of Bitcoin holders with {} — that makes me a {}! 🐋",
+        format_percentile(user_result.percentile),
+        format_bitcoin_amount(user_result.user_bitcoin_amount),
+        wealth_category_name(user_result.percentile)
+    )
+}
+
+/// Build a shareable URL that reopens the site with `amount` prefilled and auto-calculated,
+/// via the `amount` query parameter the app reads on load
+fn build_share_url(amount: f64) -> Option<String> {
+    let location = window()?.location();
+    let origin = location.origin().ok()?;
+    let pathname = location.pathname().ok()?;
+    let encoded_amount = js_sys::encode_uri_component(&format!("{}", amount));
+    Some(format!("{}{}?amount={}", origin, pathname, encoded_amount))
+}
+
+/// Copy `text` to the clipboard via the async Clipboard API
+async fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+    let clipboard = window().ok_or("No window available")?.navigator().clipboard();
+    JsFuture::from(clipboard.write_text(text)).await?;
+    Ok(())
+}
+
+/// Wrap `content` in a Blob of the given MIME type and trigger a browser download via an
+/// object URL and a synthetic `<a download>` click
+fn trigger_blob_download(content: &str, filename: &str, mime_type: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::new();
+    parts.push(&JsValue::from_str(content));
+
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let result = trigger_anchor_download(&url, filename);
+    web_sys::Url::revoke_object_url(&url)?;
+
+    result
+}
+
+/// Trigger a browser download directly from a `data:` URL (e.g. `canvas.to_data_url`)
+fn trigger_data_url_download(data_url: &str, filename: &str) -> Result<(), JsValue> {
+    trigger_anchor_download(data_url, filename)
+}
+
+fn trigger_anchor_download(href: &str, filename: &str) -> Result<(), JsValue> {
+    let document = window()
+        .ok_or("No window available")?
+        .document()
+        .ok_or("No document available")?;
+
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()
+        .map_err(|_| JsValue::from_str("Failed to create anchor element"))?;
+    anchor.set_href(href);
+    anchor.set_download(filename);
+    anchor.click();
+
+    Ok(())
+}
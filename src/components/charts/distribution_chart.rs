@@ -1,56 +1,471 @@
+use crate::services::data_processor::DataProcessor;
 use crate::types::bitcoin::{BitcoinDistribution, WealthRange};
-use crate::utils::chart_theme::{format_bitcoin_amount, format_large_number, MempoolChartTheme};
+use crate::utils::chart_theme::{
+    format_bitcoin_amount, format_fiat_amount, format_large_number, is_dark_theme, ChartPalette,
+    MempoolChartTheme,
+};
+use crate::utils::theme::{Theme, ThemeManager};
+use gloo_timers::future::TimeoutFuture;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
+use std::collections::{HashMap, HashSet};
+use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlCanvasElement;
 use yew::prelude::*;
 
+/// How much `progress` advances per animation tick while autoplaying
+const AUTOPLAY_STEP: f64 = 0.04;
+const AUTOPLAY_TICK_MS: u32 = 60;
+
+/// A distribution captured at a point in time (milliseconds since epoch), as used for
+/// scrubbing/animating through historical snapshots
+pub type DistributionSnapshot = (u64, BitcoinDistribution);
+
+/// Cached plotting geometry needed to hit-test a pointer position against the chart
+/// without re-rendering the canvas. `plot_left`/`plot_right` are pixel bounds of the
+/// log-scale BTC axis; `min_btc`/`max_btc` are that axis's domain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ChartTransform {
+    min_btc: f64,
+    max_btc: f64,
+    plot_left: i32,
+    plot_right: i32,
+    plot_top: i32,
+    plot_bottom: i32,
+}
+
+impl ChartTransform {
+    /// Convert a canvas-relative pixel x back through the log-scale domain to a BTC amount
+    fn pixel_x_to_btc(&self, pixel_x: i32) -> Option<f64> {
+        if pixel_x < self.plot_left || pixel_x > self.plot_right || self.plot_right <= self.plot_left {
+            return None;
+        }
+        let fraction = (pixel_x - self.plot_left) as f64 / (self.plot_right - self.plot_left) as f64;
+        let log_min = self.min_btc.ln();
+        let log_max = self.max_btc.ln();
+        Some((log_min + fraction * (log_max - log_min)).exp())
+    }
+}
+
+/// Range currently under the pointer, plus where to anchor the tooltip/crosshair
+#[derive(Debug, Clone, PartialEq)]
+struct HoverState {
+    range: WealthRange,
+    pixel_x: i32,
+    pixel_y: i32,
+    /// Cumulative share of addresses at or below this bucket's upper boundary, used to gauge
+    /// the bucket's distance from `user_percentile`
+    bucket_percentile: f64,
+}
+
+/// Describe how far a hovered bucket sits from the user's own percentile, for the tooltip's
+/// last line when `user_percentile` is present
+fn format_percentile_distance(bucket_percentile: f64, user_percentile: f64) -> String {
+    let distance = bucket_percentile - user_percentile;
+    if distance.abs() < 0.5 {
+        "About the same as your percentile".to_string()
+    } else if distance > 0.0 {
+        format!("{:.1} points above your percentile", distance)
+    } else {
+        format!("{:.1} points below your percentile", -distance)
+    }
+}
+
+/// How `DistributionChart` plots the address data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartMode {
+    /// Bars of address count per BTC range
+    Histogram,
+    /// Monotonically increasing curve of cumulative address share
+    Cumulative,
+}
+
+impl Default for ChartMode {
+    fn default() -> Self {
+        ChartMode::Histogram
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct DistributionChartProps {
     pub distribution: BitcoinDistribution,
     pub user_amount: Option<f64>,
     pub user_percentile: Option<f64>,
+    /// Optional history of snapshots to scrub/animate through. When present, it drives the
+    /// chart instead of `distribution`; `distribution` remains the fallback for static use.
+    #[prop_or_default]
+    pub timeline: Option<Vec<DistributionSnapshot>>,
+    /// Spot price used to convert BTC thresholds to fiat for the secondary axis labels.
+    /// When `None`, the chart renders BTC-only as before.
+    #[prop_or_default]
+    pub fiat_rate: Option<f64>,
+    /// Currency symbol prepended to fiat amounts (e.g. `"$"`)
+    #[prop_or_else(default_fiat_symbol)]
+    pub fiat_symbol: String,
+    /// Active color theme for the canvas. When `None`, the chart detects the page's
+    /// current `dark`/`light` state itself (e.g. on first mount before a parent wires this up).
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+    /// Message shown instead of the chart when `distribution.ranges` has no data
+    #[prop_or_else(default_empty_text)]
+    pub empty_text: String,
+}
+
+fn default_fiat_symbol() -> String {
+    "$".to_string()
+}
+
+fn default_empty_text() -> String {
+    "No distribution data available".to_string()
+}
+
+/// Render a tick/annotation label for a BTC amount, appending the fiat-converted value in
+/// parentheses when a spot price is available
+fn format_amount_label(btc: f64, fiat_rate: Option<f64>, fiat_symbol: &str) -> String {
+    match fiat_rate {
+        Some(rate) => format!(
+            "{} (~{})",
+            format_bitcoin_amount(btc),
+            format_fiat_amount(btc * rate, fiat_symbol)
+        ),
+        None => format_bitcoin_amount(btc),
+    }
+}
+
+/// Blend two `u64` counters at `progress` in `[0.0, 1.0]`
+fn lerp_u64(from: u64, to: u64, progress: f64) -> u64 {
+    (from as f64 + (to as f64 - from as f64) * progress).round() as u64
+}
+
+/// Blend two `f64` quantities at `progress` in `[0.0, 1.0]`
+fn lerp_f64(from: f64, to: f64, progress: f64) -> f64 {
+    from + (to - from) * progress
+}
+
+/// A range with the same `min_btc`/`max_btc` bucket but every count/share zeroed out, used as
+/// the "from" or "to" endpoint when a bucket only exists in one of the two snapshots
+fn zeroed_range(range: &WealthRange) -> WealthRange {
+    WealthRange {
+        min_btc: range.min_btc,
+        max_btc: range.max_btc,
+        address_count: 0,
+        total_btc: 0.0,
+        percentage_of_addresses: 0.0,
+        percentage_of_supply: 0.0,
+    }
+}
+
+fn lerp_range(from: &WealthRange, to: &WealthRange, progress: f64) -> WealthRange {
+    WealthRange {
+        min_btc: from.min_btc,
+        max_btc: from.max_btc,
+        address_count: lerp_u64(from.address_count, to.address_count, progress),
+        total_btc: lerp_f64(from.total_btc, to.total_btc, progress),
+        percentage_of_addresses: lerp_f64(
+            from.percentage_of_addresses,
+            to.percentage_of_addresses,
+            progress,
+        ),
+        percentage_of_supply: lerp_f64(
+            from.percentage_of_supply,
+            to.percentage_of_supply,
+            progress,
+        ),
+    }
+}
+
+/// Interpolate between two distribution snapshots at `progress` in `[0.0, 1.0]`, aligning
+/// ranges by their `(min_btc, max_btc)` bucket so bars morph smoothly even when the snapshots'
+/// bucket boundaries differ. A bucket present in only one snapshot fades in/out from zero
+/// rather than popping into existence.
+fn interpolate_distribution(
+    from: &BitcoinDistribution,
+    to: &BitcoinDistribution,
+    progress: f64,
+) -> BitcoinDistribution {
+    let progress = progress.clamp(0.0, 1.0);
+    let bucket_key = |range: &WealthRange| (range.min_btc.to_bits(), range.max_btc.to_bits());
+
+    let to_by_bucket: HashMap<_, _> = to.ranges.iter().map(|range| (bucket_key(range), range)).collect();
+    let mut seen_buckets = HashSet::new();
+
+    let mut ranges: Vec<WealthRange> = from
+        .ranges
+        .iter()
+        .map(|from_range| {
+            let key = bucket_key(from_range);
+            seen_buckets.insert(key);
+            match to_by_bucket.get(&key) {
+                Some(to_range) => lerp_range(from_range, to_range, progress),
+                None => lerp_range(from_range, &zeroed_range(from_range), progress),
+            }
+        })
+        .collect();
+
+    for to_range in &to.ranges {
+        if !seen_buckets.contains(&bucket_key(to_range)) {
+            ranges.push(lerp_range(&zeroed_range(to_range), to_range, progress));
+        }
+    }
+
+    BitcoinDistribution {
+        ranges,
+        total_addresses: lerp_u64(from.total_addresses, to.total_addresses, progress),
+        total_supply: lerp_f64(from.total_supply, to.total_supply, progress),
+        timestamp: lerp_u64(from.timestamp, to.timestamp, progress),
+        data_source: if progress < 0.5 {
+            from.data_source.clone()
+        } else {
+            to.data_source.clone()
+        },
+    }
+}
+
+/// Resolve the distribution to render at a continuous timeline position, where the integer
+/// part of `index` selects the lower bracketing snapshot and the fractional part is the
+/// interpolation progress toward the next one.
+fn distribution_at(timeline: &[DistributionSnapshot], index: f64) -> BitcoinDistribution {
+    let last = timeline.len() - 1;
+    let index = index.clamp(0.0, last as f64);
+    let lower = index.floor() as usize;
+    let upper = (lower + 1).min(last);
+    let progress = index - lower as f64;
+
+    if lower == upper {
+        timeline[lower].1.clone()
+    } else {
+        interpolate_distribution(&timeline[lower].1, &timeline[upper].1, progress)
+    }
 }
 
 #[function_component(DistributionChart)]
 pub fn distribution_chart(props: &DistributionChartProps) -> Html {
     let canvas_ref = use_node_ref();
+    let lorenz_canvas_ref = use_node_ref();
+    let transform = use_mut_ref(|| None::<ChartTransform>);
+    let plotted_ranges = use_mut_ref(Vec::<WealthRange>::new);
+    let hovered = use_state(|| None::<HoverState>);
+    let data_processor = use_state(DataProcessor::new);
+    let chart_mode = use_state(ChartMode::default);
+
+    let timeline_progress = use_state(|| 0.0_f64);
+    let playing = use_state(|| false);
+    let play_epoch = use_mut_ref(|| 0u32);
+
+    let effective_distribution = match &props.timeline {
+        Some(timeline) if !timeline.is_empty() => distribution_at(timeline, *timeline_progress),
+        _ => props.distribution.clone(),
+    };
+
+    let gini_coefficient = data_processor.calculate_gini_coefficient(&effective_distribution);
+
+    let active_theme = props.theme.unwrap_or_else(|| {
+        if is_dark_theme() {
+            Theme::Dark
+        } else {
+            Theme::Light
+        }
+    });
 
     // Effect to draw the chart when data changes
     {
         let canvas_ref = canvas_ref.clone();
-        let distribution = props.distribution.clone();
+        let distribution = effective_distribution.clone();
         let user_amount = props.user_amount;
         let user_percentile = props.user_percentile;
+        let transform = transform.clone();
+        let plotted_ranges = plotted_ranges.clone();
+        let chart_mode = *chart_mode;
+        let fiat_rate = props.fiat_rate;
+        let fiat_symbol = props.fiat_symbol.clone();
 
         use_effect_with(
-            (distribution.clone(), user_amount, user_percentile),
+            (
+                distribution.clone(),
+                user_amount,
+                user_percentile,
+                chart_mode,
+                fiat_rate,
+                fiat_symbol.clone(),
+                active_theme,
+            ),
             move |_| {
                 if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
-                    let _ = draw_distribution_chart(
+                    if let Ok(result) = draw_distribution_chart(
                         canvas,
                         &distribution,
                         user_amount,
                         user_percentile,
-                    );
+                        chart_mode,
+                        fiat_rate,
+                        &fiat_symbol,
+                        active_theme,
+                    ) {
+                        *transform.borrow_mut() = Some(result.transform);
+                        *plotted_ranges.borrow_mut() = result.ranges;
+                    }
                 }
                 || ()
             },
         );
     }
 
+    // Effect to draw the Lorenz curve whenever the distribution or theme changes
+    {
+        let lorenz_canvas_ref = lorenz_canvas_ref.clone();
+        let distribution = effective_distribution.clone();
+        let data_processor = data_processor.clone();
+
+        use_effect_with((distribution.clone(), active_theme), move |_| {
+            if let Some(canvas) = lorenz_canvas_ref.cast::<HtmlCanvasElement>() {
+                let points = data_processor.lorenz_curve_points(&distribution);
+                let _ = draw_lorenz_curve(canvas, &points, active_theme);
+            }
+            || ()
+        });
+    }
+
+    let total_addresses = effective_distribution.total_addresses;
+    let timeline_len = props.timeline.as_ref().map(Vec::len).unwrap_or(0);
+
+    let sorted_ranges = {
+        let mut ranges = effective_distribution.ranges.clone();
+        ranges.sort_by(|a, b| a.min_btc.partial_cmp(&b.min_btc).unwrap());
+        ranges
+    };
+
+    let on_scrub = {
+        let timeline_progress = timeline_progress.clone();
+        let playing = playing.clone();
+        let play_epoch = play_epoch.clone();
+        Callback::from(move |event: InputEvent| {
+            playing.set(false);
+            *play_epoch.borrow_mut() += 1;
+            let input = event.target_unchecked_into::<web_sys::HtmlInputElement>();
+            if let Ok(value) = input.value().parse::<f64>() {
+                timeline_progress.set(value);
+            }
+        })
+    };
+
+    let on_toggle_play = {
+        let playing = playing.clone();
+        let timeline_progress = timeline_progress.clone();
+        let play_epoch = play_epoch.clone();
+        Callback::from(move |_: MouseEvent| {
+            if timeline_len < 2 {
+                return;
+            }
+
+            let now_playing = !*playing;
+            playing.set(now_playing);
+
+            if now_playing {
+                *play_epoch.borrow_mut() += 1;
+                let my_epoch = *play_epoch.borrow();
+                let playing = playing.clone();
+                let timeline_progress = timeline_progress.clone();
+                let play_epoch = play_epoch.clone();
+
+                spawn_local(async move {
+                    loop {
+                        TimeoutFuture::new(AUTOPLAY_TICK_MS).await;
+                        if *play_epoch.borrow() != my_epoch || !*playing {
+                            break;
+                        }
+
+                        let last_index = (timeline_len - 1) as f64;
+                        let next = *timeline_progress + AUTOPLAY_STEP;
+                        if next >= last_index {
+                            timeline_progress.set(last_index);
+                            playing.set(false);
+                            break;
+                        }
+                        timeline_progress.set(next);
+                    }
+                });
+            }
+        })
+    };
+
+    let onmousemove = {
+        let transform = transform.clone();
+        let plotted_ranges = plotted_ranges.clone();
+        let hovered = hovered.clone();
+        Callback::from(move |event: MouseEvent| {
+            let pixel_x = event.offset_x();
+            let pixel_y = event.offset_y();
+
+            let next = transform.borrow().as_ref().and_then(|t| {
+                let btc_amount = t.pixel_x_to_btc(pixel_x)?;
+                let ranges = plotted_ranges.borrow();
+                let index = ranges
+                    .iter()
+                    .position(|range| btc_amount >= range.min_btc && btc_amount < range.max_btc)?;
+
+                let bucket_percentile: f64 =
+                    ranges[..=index].iter().map(|range| range.percentage_of_addresses).sum();
+
+                Some(HoverState {
+                    range: ranges[index].clone(),
+                    pixel_x: pixel_x.clamp(t.plot_left, t.plot_right),
+                    pixel_y: pixel_y.clamp(t.plot_top, t.plot_bottom),
+                    bucket_percentile,
+                })
+            });
+
+            hovered.set(next);
+        })
+    };
+
+    let onmouseleave = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(None))
+    };
+
+    let on_toggle_mode = {
+        let chart_mode = chart_mode.clone();
+        Callback::from(move |_: MouseEvent| {
+            chart_mode.set(match *chart_mode {
+                ChartMode::Histogram => ChartMode::Cumulative,
+                ChartMode::Cumulative => ChartMode::Histogram,
+            });
+        })
+    };
+
+    // Checked after every hook above has run, so the early return never disturbs hook order
+    if effective_distribution.ranges.is_empty() {
+        return html! {
+            <div class="bg-white dark:bg-gray-800 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 flex items-center justify-center min-h-[300px]">
+                <p class="text-lg text-gray-500 dark:text-gray-400">{&props.empty_text}</p>
+            </div>
+        };
+    }
+
     html! {
         <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
             // Enhanced Header Section
             <div class="mb-8">
-                <div class="flex items-center mb-4">
-                    <div class="text-3xl mr-3">{"📊"}</div>
-                    <div>
-                        <h3 class="text-3xl font-bold text-gray-900 dark:text-white">{"Bitcoin Distribution Chart"}</h3>
-                        <p class="text-lg text-gray-600 dark:text-gray-300">{"Visualizing global Bitcoin wealth distribution"}</p>
+                <div class="flex items-center justify-between mb-4">
+                    <div class="flex items-center">
+                        <div class="text-3xl mr-3">{"📊"}</div>
+                        <div>
+                            <h3 class="text-3xl font-bold text-gray-900 dark:text-white">{"Bitcoin Distribution Chart"}</h3>
+                            <p class="text-lg text-gray-600 dark:text-gray-300">{"Visualizing global Bitcoin wealth distribution"}</p>
+                        </div>
                     </div>
+
+                    <button
+                        class="px-4 py-2 text-sm font-semibold rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-700 dark:text-gray-200 transition-colors duration-200"
+                        onclick={on_toggle_mode}
+                    >
+                        {match *chart_mode {
+                            ChartMode::Histogram => "📈 Show Cumulative",
+                            ChartMode::Cumulative => "📊 Show Histogram",
+                        }}
+                    </button>
                 </div>
-                
+
                 // Key Statistics Cards
                 <div class="grid grid-cols-1 md:grid-cols-3 gap-4 mb-6">
                     <div class="bg-gradient-to-br from-blue-500 to-blue-600 rounded-xl p-4 text-white transform hover:scale-105 transition-transform duration-300 shadow-lg">
@@ -58,7 +473,7 @@ pub fn distribution_chart(props: &DistributionChartProps) -> Html {
                             <div class="text-2xl">{"🏦"}</div>
                             <div class="text-xs opacity-75 bg-white/20 px-2 py-1 rounded-full">{"ADDRESSES"}</div>
                         </div>
-                        <div class="text-2xl font-bold mb-1">{format!("{}M", (props.distribution.total_addresses as f64 / 1_000_000.0).round())}</div>
+                        <div class="text-2xl font-bold mb-1">{format!("{}M", (effective_distribution.total_addresses as f64 / 1_000_000.0).round())}</div>
                         <div class="text-sm opacity-90">{"Total Bitcoin Addresses"}</div>
                     </div>
                     
@@ -67,7 +482,7 @@ pub fn distribution_chart(props: &DistributionChartProps) -> Html {
                             <div class="text-2xl">{"₿"}</div>
                             <div class="text-xs opacity-75 bg-white/20 px-2 py-1 rounded-full">{"SUPPLY"}</div>
                         </div>
-                        <div class="text-2xl font-bold mb-1">{format!("{:.1}M", props.distribution.total_supply / 1_000_000.0)}</div>
+                        <div class="text-2xl font-bold mb-1">{format!("{:.1}M", effective_distribution.total_supply / 1_000_000.0)}</div>
                         <div class="text-sm opacity-90">{"Bitcoin in Circulation"}</div>
                     </div>
                     
@@ -76,10 +491,33 @@ pub fn distribution_chart(props: &DistributionChartProps) -> Html {
                             <div class="text-2xl">{"📈"}</div>
                             <div class="text-xs opacity-75 bg-white/20 px-2 py-1 rounded-full">{"CONCENTRATION"}</div>
                         </div>
-                        <div class="text-2xl font-bold mb-1">{"High"}</div>
-                        <div class="text-sm opacity-90">{"Wealth Distribution"}</div>
+                        <div class="text-2xl font-bold mb-1">{format!("{:.3}", gini_coefficient)}</div>
+                        <div class="text-sm opacity-90">{"Gini Coefficient"}</div>
                     </div>
                 </div>
+
+                if timeline_len > 1 {
+                    <div class="flex items-center gap-4 bg-gray-50 dark:bg-gray-900 rounded-xl p-4">
+                        <button
+                            class="px-3 py-2 rounded-lg bg-orange-500 hover:bg-orange-600 text-white font-semibold transition-colors duration-200"
+                            onclick={on_toggle_play}
+                        >
+                            {if *playing { "⏸" } else { "▶" }}
+                        </button>
+                        <input
+                            type="range"
+                            min="0"
+                            max={(timeline_len - 1).to_string()}
+                            step="0.01"
+                            value={timeline_progress.to_string()}
+                            oninput={on_scrub}
+                            class="flex-1"
+                        />
+                        <span class="text-sm text-gray-600 dark:text-gray-300 whitespace-nowrap">
+                            {format!("Snapshot {:.0}/{}", *timeline_progress + 1.0, timeline_len)}
+                        </span>
+                    </div>
+                }
             </div>
 
             // Chart Container with Enhanced Styling
@@ -88,10 +526,33 @@ pub fn distribution_chart(props: &DistributionChartProps) -> Html {
                     ref={canvas_ref}
                     width="800"
                     height="400"
-                    class="w-full h-auto rounded-lg"
+                    class="w-full h-auto rounded-lg cursor-crosshair"
                     style="max-width: 100%; height: auto;"
+                    onmousemove={onmousemove}
+                    onmouseleave={onmouseleave}
                 />
-                
+
+                if let Some(hover) = (*hovered).clone() {
+                    <div
+                        class="absolute top-0 bottom-0 w-px bg-gray-400 dark:bg-gray-500 pointer-events-none"
+                        style={format!("left: {}px;", hover.pixel_x)}
+                    />
+                    <div
+                        class="absolute bg-gray-900 dark:bg-gray-100 text-white dark:text-gray-900 text-sm rounded-lg shadow-xl px-3 py-2 pointer-events-none z-10"
+                        style={format!("left: {}px; top: {}px; transform: translate(-50%, -110%);", hover.pixel_x, hover.pixel_y)}
+                    >
+                        <div class="font-semibold">
+                            {format!("{} – {}", format_bitcoin_amount(hover.range.min_btc), format_bitcoin_amount(hover.range.max_btc))}
+                        </div>
+                        <div>{format!("{} addresses", format_large_number(hover.range.address_count as f64))}</div>
+                        <div>{format!("{:.2}% of all addresses", (hover.range.address_count as f64 / total_addresses.max(1) as f64) * 100.0)}</div>
+                        <div>{format!("{:.2}% of supply", hover.range.percentage_of_supply)}</div>
+                        if let Some(user_percentile) = props.user_percentile {
+                            <div>{format_percentile_distance(hover.bucket_percentile, user_percentile)}</div>
+                        }
+                    </div>
+                }
+
                 // Enhanced Legend with Modern Design
                 <div class="mt-6 bg-gray-50 dark:bg-gray-900 rounded-xl p-4">
                     <h4 class="text-lg font-semibold mb-3 text-gray-900 dark:text-white flex items-center">
@@ -128,7 +589,60 @@ pub fn distribution_chart(props: &DistributionChartProps) -> Html {
                         </div>
                     </div>
                 </div>
-                
+
+                // Full Range Legend — every range, not just a fixed slice, in a scrollable
+                // container so the list stays explorable without growing the card itself
+                <div class="mt-6 bg-gray-50 dark:bg-gray-900 rounded-xl p-4">
+                    <h4 class="text-lg font-semibold mb-3 text-gray-900 dark:text-white flex items-center">
+                        <div class="text-xl mr-2">{"📋"}</div>
+                        {"All Ranges"}
+                    </h4>
+                    <div class="space-y-2 overflow-y-auto max-h-64 pr-1">
+                        { for sorted_ranges.iter().map(|range| {
+                            let max_display = if range.max_btc == f64::INFINITY {
+                                "∞".to_string()
+                            } else {
+                                format!("{:.3}", range.max_btc)
+                            };
+
+                            html! {
+                                <div class="flex items-center justify-between p-3 bg-white dark:bg-gray-800 rounded-lg">
+                                    <div class="flex-1">
+                                        <div class="font-semibold text-gray-900 dark:text-white">
+                                            {format!("{:.3} - {} BTC", range.min_btc, max_display)}
+                                        </div>
+                                        <div class="text-sm text-gray-600 dark:text-gray-300">
+                                            {format!("{} addresses ({:.1}%)", format_large_number(range.address_count as f64), range.percentage_of_addresses)}
+                                        </div>
+                                    </div>
+                                    <div class="text-right">
+                                        <div class="font-semibold text-gray-900 dark:text-white">{format!("{:.1}%", range.percentage_of_supply)}</div>
+                                        <div class="text-sm text-gray-600 dark:text-gray-300">{"of supply"}</div>
+                                    </div>
+                                </div>
+                            }
+                        }) }
+                    </div>
+                </div>
+
+                // Lorenz Curve Panel
+                <div class="mt-6 bg-gray-50 dark:bg-gray-900 rounded-xl p-4">
+                    <h4 class="text-lg font-semibold mb-3 text-gray-900 dark:text-white flex items-center">
+                        <div class="text-xl mr-2">{"📉"}</div>
+                        {"Lorenz Curve"}
+                    </h4>
+                    <canvas
+                        ref={lorenz_canvas_ref}
+                        width="400"
+                        height="400"
+                        class="w-full max-w-sm mx-auto h-auto rounded-lg"
+                        style="max-width: 100%; height: auto;"
+                    />
+                    <p class="text-sm text-gray-600 dark:text-gray-300 text-center mt-2">
+                        {format!("Gini coefficient: {:.3} — the further the curve bows from the diagonal, the more concentrated the wealth.", gini_coefficient)}
+                    </p>
+                </div>
+
                 // Insights Section
                 if props.user_amount.is_some() && props.user_percentile.is_some() {
                     <div class="mt-6 bg-gradient-to-r from-green-50 to-emerald-50 dark:from-green-900/20 dark:to-emerald-900/20 rounded-xl p-4 border border-green-200 dark:border-green-800">
@@ -146,17 +660,70 @@ pub fn distribution_chart(props: &DistributionChartProps) -> Html {
     }
 }
 
+/// Output of a draw pass that the component caches for pointer hit-testing
+struct ChartDrawResult {
+    transform: ChartTransform,
+    ranges: Vec<WealthRange>,
+}
+
 fn draw_distribution_chart(
     canvas: HtmlCanvasElement,
     distribution: &BitcoinDistribution,
     user_amount: Option<f64>,
     user_percentile: Option<f64>,
-) -> Result<(), Box<dyn std::error::Error>> {
+    mode: ChartMode,
+    fiat_rate: Option<f64>,
+    fiat_symbol: &str,
+    theme: Theme,
+) -> Result<ChartDrawResult, Box<dyn std::error::Error>> {
+    match mode {
+        ChartMode::Histogram => draw_histogram_chart(
+            canvas,
+            distribution,
+            user_amount,
+            user_percentile,
+            fiat_rate,
+            fiat_symbol,
+            theme,
+        ),
+        ChartMode::Cumulative => draw_cumulative_chart(
+            canvas,
+            distribution,
+            user_amount,
+            user_percentile,
+            fiat_rate,
+            fiat_symbol,
+            theme,
+        ),
+    }
+}
+
+/// Resolve the active theme for the given explicit light/dark `theme` prop, honoring the
+/// user's selected named palette (see `ThemeManager::save_chart_theme_config`) the same way
+/// `MempoolChartTheme::current` does for callers that resolve ambiently.
+fn theme_for(theme: Theme) -> MempoolChartTheme {
+    let palette = ThemeManager::get_stored_chart_theme_config()
+        .and_then(|config| config.extends)
+        .and_then(|name| ChartPalette::from_str(&name))
+        .unwrap_or_default();
+
+    MempoolChartTheme::for_palette(palette, theme == Theme::Dark)
+}
+
+fn draw_histogram_chart(
+    canvas: HtmlCanvasElement,
+    distribution: &BitcoinDistribution,
+    user_amount: Option<f64>,
+    user_percentile: Option<f64>,
+    fiat_rate: Option<f64>,
+    fiat_symbol: &str,
+    theme: Theme,
+) -> Result<ChartDrawResult, Box<dyn std::error::Error>> {
     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
     let root = backend.into_drawing_area();
 
-    // Get mempool.space inspired theme
-    let theme = MempoolChartTheme::new();
+    // Match the page's active color theme instead of always rendering light
+    let theme = theme_for(theme);
     root.fill(&theme.background)?;
 
     // Filter out ranges with very few addresses for better visualization
@@ -170,7 +737,7 @@ fn draw_distribution_chart(
     filtered_ranges.sort_by(|a, b| a.min_btc.partial_cmp(&b.min_btc).unwrap());
 
     if filtered_ranges.is_empty() {
-        return Ok(());
+        return Err("no ranges to plot".into());
     }
 
     // Calculate chart bounds
@@ -192,11 +759,31 @@ fn draw_distribution_chart(
         .y_label_area_size(80)
         .build_cartesian_2d((min_btc..max_btc).log_scale(), 0u64..max_addresses)?;
 
+    let (plot_x_range, plot_y_range) = chart.plotting_area().get_pixel_range();
+    let transform = ChartTransform {
+        min_btc,
+        max_btc,
+        plot_left: plot_x_range.start,
+        plot_right: plot_x_range.end,
+        plot_top: plot_y_range.start,
+        plot_bottom: plot_y_range.end,
+    };
+
     chart
         .configure_mesh()
-        .x_desc("Bitcoin Amount (BTC)")
+        .x_desc(if fiat_rate.is_some() {
+            "Bitcoin Amount (BTC, ~fiat)"
+        } else {
+            "Bitcoin Amount (BTC)"
+        })
         .y_desc("Number of Addresses")
-        .x_label_formatter(&|x| format_bitcoin_amount(*x).replace(" BTC", ""))
+        .x_label_formatter(&|x| {
+            if let Some(rate) = fiat_rate {
+                format_amount_label(*x, Some(rate), fiat_symbol)
+            } else {
+                format_bitcoin_amount(*x).replace(" BTC", "")
+            }
+        })
         .y_label_formatter(&|y| format_large_number(*y as f64))
         .label_style(theme.create_secondary_text_style(12))
         .axis_style(&theme.grid_color)
@@ -242,7 +829,11 @@ fn draw_distribution_chart(
 
             // Add user percentile label with better positioning
             if let Some(percentile) = user_percentile {
-                let label_text = format!("You: {:.2}%", percentile);
+                let label_text = format!(
+                    "You: {:.2}% — {}",
+                    percentile,
+                    format_amount_label(amount, fiat_rate, fiat_symbol)
+                );
                 let label_y = max_addresses as u64 * 85 / 100; // Position at 85% height
 
                 chart.draw_series(std::iter::once(Text::new(
@@ -283,6 +874,218 @@ fn draw_distribution_chart(
         .label_font(theme.create_secondary_text_style(12))
         .draw()?;
 
+    root.present()?;
+    Ok(ChartDrawResult {
+        transform,
+        ranges: filtered_ranges.into_iter().cloned().collect(),
+    })
+}
+
+fn draw_cumulative_chart(
+    canvas: HtmlCanvasElement,
+    distribution: &BitcoinDistribution,
+    user_amount: Option<f64>,
+    user_percentile: Option<f64>,
+    fiat_rate: Option<f64>,
+    fiat_symbol: &str,
+    theme: Theme,
+) -> Result<ChartDrawResult, Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+
+    let theme = theme_for(theme);
+    root.fill(&theme.background)?;
+
+    let mut filtered_ranges: Vec<&WealthRange> = distribution
+        .ranges
+        .iter()
+        .filter(|range| range.address_count > 100 && range.max_btc < 1000000.0)
+        .collect();
+
+    filtered_ranges.sort_by(|a, b| a.min_btc.partial_cmp(&b.min_btc).unwrap());
+
+    if filtered_ranges.is_empty() {
+        return Err("no ranges to plot".into());
+    }
+
+    let min_btc = filtered_ranges.first().unwrap().min_btc.max(0.0001);
+    let max_btc = filtered_ranges.last().unwrap().max_btc.min(10000.0);
+    let total_addresses = distribution.total_addresses.max(1) as f64;
+
+    // Walk the sorted ranges accumulating address_count into a running percentage
+    let mut cumulative_addresses = 0u64;
+    let mut curve_points = vec![(min_btc, 0.0)];
+    for range in &filtered_ranges {
+        cumulative_addresses += range.address_count;
+        let cumulative_pct = cumulative_addresses as f64 / total_addresses * 100.0;
+        curve_points.push((range.max_btc.min(max_btc), cumulative_pct));
+    }
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(
+            "Cumulative Bitcoin Holders Distribution",
+            theme.create_text_style(20),
+        )
+        .margin(15)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d((min_btc..max_btc).log_scale(), 0.0..100.0)?;
+
+    let (plot_x_range, plot_y_range) = chart.plotting_area().get_pixel_range();
+    let transform = ChartTransform {
+        min_btc,
+        max_btc,
+        plot_left: plot_x_range.start,
+        plot_right: plot_x_range.end,
+        plot_top: plot_y_range.start,
+        plot_bottom: plot_y_range.end,
+    };
+
+    chart
+        .configure_mesh()
+        .x_desc(if fiat_rate.is_some() {
+            "Bitcoin Amount (BTC, ~fiat)"
+        } else {
+            "Bitcoin Amount (BTC)"
+        })
+        .y_desc("Cumulative addresses (% ≤ amount)")
+        .x_label_formatter(&|x| {
+            if let Some(rate) = fiat_rate {
+                format_amount_label(*x, Some(rate), fiat_symbol)
+            } else {
+                format_bitcoin_amount(*x).replace(" BTC", "")
+            }
+        })
+        .y_label_formatter(&|y| format!("{:.0}%", y))
+        .label_style(theme.create_secondary_text_style(12))
+        .axis_style(&theme.grid_color)
+        .draw()?;
+
+    chart
+        .draw_series(std::iter::once(PathElement::new(
+            curve_points.clone(),
+            theme.bitcoin_orange.stroke_width(3),
+        )))?
+        .label("Cumulative Addresses")
+        .legend(|(x, y)| {
+            PathElement::new(vec![(x, y), (x + 15, y)], theme.bitcoin_orange.stroke_width(3))
+        });
+
+    // Overlay horizontal percentile guides intersecting the curve
+    let percentiles = vec![50.0, 75.0, 90.0, 95.0, 99.0];
+    for percentile in percentiles {
+        chart.draw_series(std::iter::once(PathElement::new(
+            vec![(min_btc, percentile), (max_btc, percentile)],
+            theme.border_secondary.stroke_width(1),
+        )))?;
+
+        if let Some(btc_amount) = calculate_percentile_amount(distribution, percentile) {
+            if btc_amount >= min_btc && btc_amount <= max_btc {
+                chart.draw_series(std::iter::once(Text::new(
+                    format!("{}%", percentile),
+                    (btc_amount * 1.02, percentile + 2.0),
+                    theme.create_muted_text_style(10),
+                )))?;
+            }
+        }
+    }
+
+    // Read the user's position directly off the curve
+    if let Some(amount) = user_amount {
+        if amount >= min_btc && amount <= max_btc {
+            let holders_below = curve_points
+                .iter()
+                .rev()
+                .find(|(btc, _)| *btc <= amount)
+                .map(|(_, pct)| *pct)
+                .unwrap_or(0.0);
+
+            chart
+                .draw_series(std::iter::once(PathElement::new(
+                    vec![(amount, 0.0), (amount, 100.0)],
+                    theme.bitcoin_orange.stroke_width(4),
+                )))?
+                .label("Your Position")
+                .legend(|(x, y)| {
+                    PathElement::new(
+                        vec![(x, y), (x + 15, y)],
+                        theme.bitcoin_orange.stroke_width(3),
+                    )
+                });
+
+            let amount_label = format_amount_label(amount, fiat_rate, fiat_symbol);
+            let label_text = match user_percentile {
+                Some(percentile) => format!(
+                    "You: {:.2}% richer ({}), {:.1}% of addresses hold less",
+                    percentile, amount_label, holders_below
+                ),
+                None => format!(
+                    "{} — {:.1}% of addresses hold less",
+                    amount_label, holders_below
+                ),
+            };
+
+            chart.draw_series(std::iter::once(Text::new(
+                label_text,
+                (amount * 1.05, 85.0),
+                theme.create_text_style(14),
+            )))?;
+        }
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(&theme.card_background.mix(0.9))
+        .border_style(&theme.border_primary)
+        .label_font(theme.create_secondary_text_style(12))
+        .draw()?;
+
+    root.present()?;
+    Ok(ChartDrawResult {
+        transform,
+        ranges: filtered_ranges.into_iter().cloned().collect(),
+    })
+}
+
+/// Draw the Lorenz curve (cumulative address share vs. cumulative BTC share) against the
+/// 45° equality line. `points` is expected to start at `(0.0, 0.0)`.
+fn draw_lorenz_curve(
+    canvas: HtmlCanvasElement,
+    points: &[(f64, f64)],
+    theme: Theme,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+
+    let theme = theme_for(theme);
+    root.fill(&theme.background)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .margin(15)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Cumulative Share of Addresses")
+        .y_desc("Cumulative Share of BTC")
+        .label_style(theme.create_secondary_text_style(10))
+        .axis_style(&theme.grid_color)
+        .draw()?;
+
+    // Line of perfect equality
+    chart.draw_series(std::iter::once(PathElement::new(
+        vec![(0.0, 0.0), (1.0, 1.0)],
+        theme.border_secondary.stroke_width(1),
+    )))?;
+
+    // The Lorenz curve itself
+    chart.draw_series(std::iter::once(PathElement::new(
+        points.to_vec(),
+        theme.bitcoin_orange.stroke_width(3),
+    )))?;
+
     root.present()?;
     Ok(())
 }
@@ -0,0 +1,152 @@
+use crate::services::data_processor::DataProcessor;
+use crate::types::bitcoin::BitcoinDistribution;
+use crate::utils::chart_theme::MempoolChartTheme;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LorenzChartProps {
+    pub distribution: BitcoinDistribution,
+    #[prop_or_default]
+    pub user_percentile: Option<f64>,
+}
+
+#[function_component(LorenzChart)]
+pub fn lorenz_chart(props: &LorenzChartProps) -> Html {
+    let canvas_ref = use_node_ref();
+    let data_processor = use_state(DataProcessor::new);
+
+    let gini_coefficient = data_processor.calculate_gini_coefficient(&props.distribution);
+
+    // Effect to draw the chart when data changes
+    {
+        let canvas_ref = canvas_ref.clone();
+        let distribution = props.distribution.clone();
+        let user_percentile = props.user_percentile;
+        let data_processor = data_processor.clone();
+
+        use_effect_with((distribution.clone(), user_percentile), move |_| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                let points = data_processor.lorenz_curve_points(&distribution);
+                let _ = draw_lorenz_chart(canvas, &points, gini_coefficient, user_percentile);
+            }
+            || ()
+        });
+    }
+
+    html! {
+        <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
+            <div class="mb-8">
+                <div class="flex items-center mb-4">
+                    <div class="text-3xl mr-3">{"⚖️"}</div>
+                    <div>
+                        <h3 class="text-3xl font-bold text-gray-900 dark:text-white">{"Wealth Inequality (Lorenz Curve)"}</h3>
+                        <p class="text-lg text-gray-600 dark:text-gray-300">{"How far Bitcoin's distribution sits from perfect equality"}</p>
+                    </div>
+                </div>
+            </div>
+
+            <div class="relative bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
+                <canvas
+                    ref={canvas_ref}
+                    width="500"
+                    height="500"
+                    class="w-full h-auto rounded-lg"
+                    style="max-width: 100%; height: auto;"
+                />
+
+                <div class="mt-4 text-center">
+                    <span class="text-sm text-gray-600 dark:text-gray-300">{"Gini coefficient: "}</span>
+                    <span class="font-semibold text-gray-900 dark:text-white">{format!("{:.3}", gini_coefficient)}</span>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn draw_lorenz_chart(
+    canvas: HtmlCanvasElement,
+    points: &[(f64, f64)],
+    gini: f64,
+    user_percentile: Option<f64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+
+    let theme = MempoolChartTheme::current();
+    root.fill(&theme.background)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(
+            format!("Lorenz Curve (Gini: {:.3})", gini),
+            theme.create_text_style(18),
+        )
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Cumulative share of addresses")
+        .y_desc("Cumulative share of BTC held")
+        .x_label_formatter(&|x| format!("{:.0}%", x * 100.0))
+        .y_label_formatter(&|y| format!("{:.0}%", y * 100.0))
+        .label_style(theme.create_secondary_text_style(12))
+        .axis_style(&theme.grid_color)
+        .draw()?;
+
+    // Shaded region between the curve and the line of perfect equality
+    chart.draw_series(std::iter::once(Polygon::new(
+        points
+            .iter()
+            .map(|(x, y)| (*x, *y))
+            .chain(points.iter().rev().map(|(x, _)| (*x, *x)))
+            .collect::<Vec<_>>(),
+        theme.bitcoin_orange.mix(0.15),
+    )))?;
+
+    // Line of perfect equality
+    chart
+        .draw_series(std::iter::once(PathElement::new(
+            vec![(0.0, 0.0), (1.0, 1.0)],
+            theme.text_muted.stroke_width(1),
+        )))?
+        .label("Perfect equality")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 15, y)], theme.text_muted));
+
+    // The Lorenz curve itself
+    chart
+        .draw_series(std::iter::once(PathElement::new(
+            points.to_vec(),
+            theme.chart_secondary.stroke_width(3),
+        )))?
+        .label("Lorenz curve")
+        .legend(|(x, y)| {
+            PathElement::new(
+                vec![(x, y), (x + 15, y)],
+                theme.chart_secondary.stroke_width(3),
+            )
+        });
+
+    // Vertical marker at the user's position on the inequality curve
+    if let Some(percentile) = user_percentile {
+        let user_x = (percentile / 100.0).clamp(0.0, 1.0);
+        chart.draw_series(std::iter::once(PathElement::new(
+            vec![(user_x, 0.0), (user_x, 1.0)],
+            theme.bitcoin_orange.stroke_width(3),
+        )))?;
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(&theme.card_background.mix(0.9))
+        .border_style(&theme.border_primary)
+        .label_font(theme.create_secondary_text_style(12))
+        .draw()?;
+
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod comparison_chart;
+pub mod distribution_chart;
+pub mod lorenz_chart;
+pub mod statistics_chart;
+pub mod trendline_chart;
+pub mod wealth_boxplot;
+pub mod wealth_treemap;
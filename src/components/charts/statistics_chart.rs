@@ -1,29 +1,160 @@
 use crate::types::bitcoin::BitcoinDistribution;
-use crate::utils::chart_theme::{format_large_number, MempoolChartTheme};
+use crate::types::currency::Currency;
+use crate::utils::chart_theme::{format_bitcoin_amount, format_large_number, MempoolChartTheme};
+use crate::utils::formatters::format_currency_amount;
+use plotters::coord::Shift;
 use plotters::prelude::*;
 use plotters_canvas::CanvasBackend;
-use web_sys::HtmlCanvasElement;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, HtmlAnchorElement, HtmlCanvasElement};
 use yew::prelude::*;
 
+/// Width/height the statistics charts are rendered at, shared by the canvas and SVG backends
+const CHART_WIDTH: u32 = 400;
+const CHART_HEIGHT: u32 = 300;
+
 #[derive(Properties, PartialEq)]
 pub struct StatisticsChartProps {
     pub distribution: BitcoinDistribution,
     pub bitcoin_price: f64,
+    /// Currency the tooltips convert `bitcoin_price`-denominated amounts into
+    #[prop_or_default]
+    pub selected_currency: Currency,
+    /// BTC price denominated in `selected_currency`, used alongside `bitcoin_price` for the
+    /// `> 0.0` gating check so tooltips can flip currency without a new gating field
+    #[prop_or_default]
+    pub currency_rate: f64,
+    /// Message shown instead of the charts when `distribution.ranges` has no data
+    #[prop_or_else(default_empty_text)]
+    pub empty_text: String,
+}
+
+fn default_empty_text() -> String {
+    "No distribution data available".to_string()
+}
+
+/// One wedge of the supply concentration pie, with the geometry needed to hit-test a pointer
+/// position and the figures needed to render its tooltip
+#[derive(Debug, Clone, PartialEq)]
+struct SupplySlice {
+    label: &'static str,
+    start_angle: f64,
+    end_angle: f64,
+    btc_amount: f64,
+    percentage: f64,
+}
+
+/// Cached pixel bounds of the pie's cartesian plane, used to convert a pointer position back to
+/// the data-space coordinates the slices were drawn in
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PieTransform {
+    plot_left: i32,
+    plot_right: i32,
+    plot_top: i32,
+    plot_bottom: i32,
+}
+
+impl PieTransform {
+    /// Convert a canvas-relative pixel position to a data-space `(x, y)` point, matching the
+    /// `-1.3..1.3` cartesian range `draw_supply_concentration_chart` plots into
+    const DATA_MIN: f64 = -1.3;
+    const DATA_MAX: f64 = 1.3;
+
+    fn pixel_to_data(&self, pixel_x: i32, pixel_y: i32) -> Option<(f64, f64)> {
+        if self.plot_right <= self.plot_left || self.plot_bottom <= self.plot_top {
+            return None;
+        }
+        let fx = (pixel_x - self.plot_left) as f64 / (self.plot_right - self.plot_left) as f64;
+        let fy = (pixel_y - self.plot_top) as f64 / (self.plot_bottom - self.plot_top) as f64;
+        let x = Self::DATA_MIN + fx * (Self::DATA_MAX - Self::DATA_MIN);
+        let y = Self::DATA_MAX - fy * (Self::DATA_MAX - Self::DATA_MIN);
+        Some((x, y))
+    }
+}
+
+/// One bar of the address range histogram, with the geometry needed to hit-test a pointer
+/// position and the figures needed to render its tooltip
+#[derive(Debug, Clone, PartialEq)]
+struct AddressBar {
+    label: String,
+    address_count: u64,
+    btc_amount: f64,
+    min_index: f64,
+    max_index: f64,
+}
+
+/// Cached pixel bounds of the bar chart's cartesian plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BarTransform {
+    plot_left: i32,
+    plot_right: i32,
+    bar_count: usize,
+}
+
+impl BarTransform {
+    fn pixel_x_to_index(&self, pixel_x: i32) -> Option<f64> {
+        if pixel_x < self.plot_left || pixel_x > self.plot_right || self.plot_right <= self.plot_left {
+            return None;
+        }
+        let fraction = (pixel_x - self.plot_left) as f64 / (self.plot_right - self.plot_left) as f64;
+        Some(fraction * self.bar_count as f64)
+    }
+}
+
+/// Which chart, and which segment of it, is currently under the pointer
+#[derive(Debug, Clone, PartialEq)]
+enum Hover {
+    Supply { slice: SupplySlice, pixel_x: i32, pixel_y: i32 },
+    Address { bar: AddressBar, pixel_x: i32, pixel_y: i32 },
+}
+
+/// How the address distribution bar chart scales its y-axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AddressChartScale {
+    /// `0..max_count`, flattens ranges that are orders of magnitude smaller than the largest
+    Linear,
+    /// `1..max_count` on a log scale, so long-tail ranges stay readable; shows every populated
+    /// range instead of only the top 8
+    Log,
+}
+
+impl Default for AddressChartScale {
+    fn default() -> Self {
+        AddressChartScale::Linear
+    }
 }
 
 #[function_component(StatisticsChart)]
 pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
     let supply_canvas_ref = use_node_ref();
     let address_canvas_ref = use_node_ref();
+    let lorenz_canvas_ref = use_node_ref();
+
+    let supply_transform = use_mut_ref(|| None::<PieTransform>);
+    let supply_slices = use_mut_ref(Vec::<SupplySlice>::new);
+    let address_transform = use_mut_ref(|| None::<BarTransform>);
+    let address_bars = use_mut_ref(Vec::<AddressBar>::new);
+    let address_scale = use_state(AddressChartScale::default);
+
+    let hovered = use_state(|| None::<Hover>);
 
     // Effect to draw the supply concentration chart
     {
         let supply_canvas_ref = supply_canvas_ref.clone();
         let distribution = props.distribution.clone();
+        let supply_transform = supply_transform.clone();
+        let supply_slices = supply_slices.clone();
+        let highlighted = match &*hovered {
+            Some(Hover::Supply { slice, .. }) => Some(slice.label),
+            _ => None,
+        };
 
-        use_effect_with(distribution.clone(), move |_| {
+        use_effect_with((distribution.clone(), highlighted), move |_| {
             if let Some(canvas) = supply_canvas_ref.cast::<HtmlCanvasElement>() {
-                let _ = draw_supply_concentration_chart(canvas, &distribution);
+                if let Ok(result) = draw_supply_concentration_chart(canvas, &distribution, highlighted) {
+                    *supply_transform.borrow_mut() = Some(result.transform);
+                    *supply_slices.borrow_mut() = result.slices;
+                }
             }
             || ()
         });
@@ -33,15 +164,188 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
     {
         let address_canvas_ref = address_canvas_ref.clone();
         let distribution = props.distribution.clone();
+        let address_transform = address_transform.clone();
+        let address_bars = address_bars.clone();
+        let scale = *address_scale;
+        let highlighted = match &*hovered {
+            Some(Hover::Address { bar, .. }) => Some(bar.label.clone()),
+            _ => None,
+        };
+
+        use_effect_with((distribution.clone(), highlighted.clone(), scale), move |_| {
+            if let Some(canvas) = address_canvas_ref.cast::<HtmlCanvasElement>() {
+                if let Ok(result) =
+                    draw_address_distribution_chart(canvas, &distribution, highlighted.as_deref(), scale)
+                {
+                    *address_transform.borrow_mut() = Some(result.transform);
+                    *address_bars.borrow_mut() = result.bars;
+                }
+            }
+            || ()
+        });
+    }
+
+    let on_toggle_address_scale = {
+        let address_scale = address_scale.clone();
+        Callback::from(move |_: MouseEvent| {
+            address_scale.set(match *address_scale {
+                AddressChartScale::Linear => AddressChartScale::Log,
+                AddressChartScale::Log => AddressChartScale::Linear,
+            });
+        })
+    };
+
+    let on_supply_mousemove = {
+        let supply_transform = supply_transform.clone();
+        let supply_slices = supply_slices.clone();
+        let hovered = hovered.clone();
+        Callback::from(move |event: MouseEvent| {
+            let pixel_x = event.offset_x();
+            let pixel_y = event.offset_y();
+
+            let next = supply_transform.borrow().as_ref().and_then(|t| {
+                let (x, y) = t.pixel_to_data(pixel_x, pixel_y)?;
+                let radius = (x * x + y * y).sqrt();
+                if radius > 1.0 {
+                    return None;
+                }
+                let angle = y.atan2(x).to_degrees().rem_euclid(360.0);
+                supply_slices
+                    .borrow()
+                    .iter()
+                    .find(|slice| angle >= slice.start_angle && angle < slice.end_angle)
+                    .map(|slice| Hover::Supply { slice: slice.clone(), pixel_x, pixel_y })
+            });
+
+            hovered.set(next);
+        })
+    };
+
+    let on_address_mousemove = {
+        let address_transform = address_transform.clone();
+        let address_bars = address_bars.clone();
+        let hovered = hovered.clone();
+        Callback::from(move |event: MouseEvent| {
+            let pixel_x = event.offset_x();
+            let pixel_y = event.offset_y();
+
+            let next = address_transform.borrow().as_ref().and_then(|t| {
+                let index = t.pixel_x_to_index(pixel_x)?;
+                address_bars
+                    .borrow()
+                    .iter()
+                    .find(|bar| index >= bar.min_index && index < bar.max_index)
+                    .map(|bar| Hover::Address { bar: bar.clone(), pixel_x, pixel_y })
+            });
+
+            hovered.set(next);
+        })
+    };
+
+    let on_mouseleave = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(None))
+    };
+
+    let on_export_supply_svg = {
+        let distribution = props.distribution.clone();
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| {
+            let highlighted = match &*hovered {
+                Some(Hover::Supply { slice, .. }) => Some(slice.label),
+                _ => None,
+            };
+            if let Ok(svg) = render_supply_concentration_chart_svg(&distribution, highlighted) {
+                let _ = trigger_blob_download(&svg, "supply-concentration-chart.svg", "image/svg+xml");
+            }
+        })
+    };
 
-        use_effect_with(distribution.clone(), move |_| {
+    let on_export_supply_png = {
+        let supply_canvas_ref = supply_canvas_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(canvas) = supply_canvas_ref.cast::<HtmlCanvasElement>() {
+                if let Ok(data_url) = canvas.to_data_url_with_type("image/png") {
+                    let _ = trigger_data_url_download(&data_url, "supply-concentration-chart.png");
+                }
+            }
+        })
+    };
+
+    let on_export_address_svg = {
+        let distribution = props.distribution.clone();
+        let hovered = hovered.clone();
+        let scale = *address_scale;
+        Callback::from(move |_: MouseEvent| {
+            let highlighted = match &*hovered {
+                Some(Hover::Address { bar, .. }) => Some(bar.label.clone()),
+                _ => None,
+            };
+            if let Ok(svg) = render_address_distribution_chart_svg(&distribution, highlighted.as_deref(), scale) {
+                let _ = trigger_blob_download(&svg, "address-distribution-chart.svg", "image/svg+xml");
+            }
+        })
+    };
+
+    let on_export_address_png = {
+        let address_canvas_ref = address_canvas_ref.clone();
+        Callback::from(move |_: MouseEvent| {
             if let Some(canvas) = address_canvas_ref.cast::<HtmlCanvasElement>() {
-                let _ = draw_address_distribution_chart(canvas, &distribution);
+                if let Ok(data_url) = canvas.to_data_url_with_type("image/png") {
+                    let _ = trigger_data_url_download(&data_url, "address-distribution-chart.png");
+                }
+            }
+        })
+    };
+
+    let bitcoin_price = props.bitcoin_price;
+    let selected_currency = props.selected_currency;
+    let currency_rate = props.currency_rate;
+
+    let (lorenz_points, gini_coefficient) = lorenz_curve_points(&props.distribution);
+
+    let on_export_lorenz_svg = {
+        let lorenz_points_for_export = lorenz_points.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Ok(svg) = render_lorenz_curve_chart_svg(&lorenz_points_for_export) {
+                let _ = trigger_blob_download(&svg, "lorenz-curve-chart.svg", "image/svg+xml");
+            }
+        })
+    };
+
+    let on_export_lorenz_png = {
+        let lorenz_canvas_ref = lorenz_canvas_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(canvas) = lorenz_canvas_ref.cast::<HtmlCanvasElement>() {
+                if let Ok(data_url) = canvas.to_data_url_with_type("image/png") {
+                    let _ = trigger_data_url_download(&data_url, "lorenz-curve-chart.png");
+                }
+            }
+        })
+    };
+
+    // Effect to draw the Lorenz curve whenever the distribution changes
+    {
+        let lorenz_canvas_ref = lorenz_canvas_ref.clone();
+        let lorenz_points = lorenz_points.clone();
+
+        use_effect_with(lorenz_points.clone(), move |_| {
+            if let Some(canvas) = lorenz_canvas_ref.cast::<HtmlCanvasElement>() {
+                let _ = draw_lorenz_curve_chart(canvas, &lorenz_points);
             }
             || ()
         });
     }
 
+    // Checked after every hook above has run, so the early return never disturbs hook order
+    if props.distribution.ranges.is_empty() {
+        return html! {
+            <div class="bg-white dark:bg-gray-800 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 flex items-center justify-center min-h-[300px]">
+                <p class="text-lg text-gray-500 dark:text-gray-400">{&props.empty_text}</p>
+            </div>
+        };
+    }
+
     html! {
         <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
             // Simplified Header Section - Cards removed to avoid duplication with main app
@@ -59,11 +363,27 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
             <div class="grid grid-cols-1 lg:grid-cols-2 gap-8">
                 // Supply Concentration Chart
                 <div class="bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
-                    <div class="flex items-center mb-4">
-                        <div class="text-2xl mr-3">{"📊"}</div>
-                        <div>
-                            <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Supply Concentration"}</h4>
-                            <p class="text-sm text-gray-600 dark:text-gray-300">{"How Bitcoin is distributed among holders"}</p>
+                    <div class="flex items-center justify-between mb-4">
+                        <div class="flex items-center">
+                            <div class="text-2xl mr-3">{"📊"}</div>
+                            <div>
+                                <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Supply Concentration"}</h4>
+                                <p class="text-sm text-gray-600 dark:text-gray-300">{"How Bitcoin is distributed among holders"}</p>
+                            </div>
+                        </div>
+                        <div class="flex gap-2">
+                            <button
+                                onclick={on_export_supply_svg}
+                                class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            >
+                                {"⬇ SVG"}
+                            </button>
+                            <button
+                                onclick={on_export_supply_png}
+                                class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            >
+                                {"⬇ PNG"}
+                            </button>
                         </div>
                     </div>
                     <div class="h-64 relative bg-gray-50 dark:bg-gray-700 rounded-lg overflow-hidden">
@@ -71,9 +391,24 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
                             ref={supply_canvas_ref.clone()}
                             width="400"
                             height="300"
-                            class="w-full h-full object-contain"
+                            class="w-full h-full object-contain cursor-pointer"
                             id="supply-chart"
+                            onmousemove={on_supply_mousemove}
+                            onmouseleave={on_mouseleave.clone()}
                         />
+                        if let Some(Hover::Supply { slice, pixel_x, pixel_y }) = &*hovered {
+                            <div
+                                class="absolute bg-gray-900 dark:bg-gray-100 text-white dark:text-gray-900 text-xs rounded-lg shadow-xl px-3 py-2 pointer-events-none z-10 whitespace-nowrap"
+                                style={format!("left: {}px; top: {}px; transform: translate(-50%, -110%);", pixel_x, pixel_y)}
+                            >
+                                <div class="font-semibold">{slice.label}</div>
+                                <div>{format_bitcoin_amount(slice.btc_amount)}</div>
+                                <div>{format!("{:.1}% of displayed supply", slice.percentage)}</div>
+                                if bitcoin_price > 0.0 {
+                                    <div>{format_currency_amount(slice.btc_amount, selected_currency, currency_rate)}</div>
+                                }
+                            </div>
+                        }
                     </div>
                     // Supply Concentration Statistics
                     <div class="mt-4 grid grid-cols-2 gap-3">
@@ -90,11 +425,36 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
 
                 // Address Distribution Chart
                 <div class="bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
-                    <div class="flex items-center mb-4">
-                        <div class="text-2xl mr-3">{"📈"}</div>
-                        <div>
-                            <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Top Address Ranges"}</h4>
-                            <p class="text-sm text-gray-600 dark:text-gray-300">{"Most common Bitcoin holding amounts"}</p>
+                    <div class="flex items-center justify-between mb-4">
+                        <div class="flex items-center">
+                            <div class="text-2xl mr-3">{"📈"}</div>
+                            <div>
+                                <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Top Address Ranges"}</h4>
+                                <p class="text-sm text-gray-600 dark:text-gray-300">{"Most common Bitcoin holding amounts"}</p>
+                            </div>
+                        </div>
+                        <div class="flex gap-2">
+                            <button
+                                class="px-3 py-1.5 text-xs font-semibold rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-700 dark:text-gray-200 transition-colors duration-200"
+                                onclick={on_toggle_address_scale}
+                            >
+                                {match *address_scale {
+                                    AddressChartScale::Linear => "📏 Log scale",
+                                    AddressChartScale::Log => "📏 Linear scale",
+                                }}
+                            </button>
+                            <button
+                                onclick={on_export_address_svg}
+                                class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            >
+                                {"⬇ SVG"}
+                            </button>
+                            <button
+                                onclick={on_export_address_png}
+                                class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            >
+                                {"⬇ PNG"}
+                            </button>
                         </div>
                     </div>
                     <div class="h-64 relative bg-gray-50 dark:bg-gray-700 rounded-lg overflow-hidden">
@@ -102,9 +462,24 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
                             ref={address_canvas_ref.clone()}
                             width="400"
                             height="300"
-                            class="w-full h-full object-contain"
+                            class="w-full h-full object-contain cursor-pointer"
                             id="address-chart"
+                            onmousemove={on_address_mousemove}
+                            onmouseleave={on_mouseleave}
                         />
+                        if let Some(Hover::Address { bar, pixel_x, pixel_y }) = &*hovered {
+                            <div
+                                class="absolute bg-gray-900 dark:bg-gray-100 text-white dark:text-gray-900 text-xs rounded-lg shadow-xl px-3 py-2 pointer-events-none z-10 whitespace-nowrap"
+                                style={format!("left: {}px; top: {}px; transform: translate(-50%, -110%);", pixel_x, pixel_y)}
+                            >
+                                <div class="font-semibold">{&bar.label}</div>
+                                <div>{format!("{} addresses", format_large_number(bar.address_count as f64))}</div>
+                                <div>{format!("~{} BTC", format_large_number(bar.btc_amount))}</div>
+                                if bitcoin_price > 0.0 {
+                                    <div>{format_currency_amount(bar.btc_amount, selected_currency, currency_rate)}</div>
+                                }
+                            </div>
+                        }
                     </div>
                     // Address Range Quick Stats
                     <div class="mt-4 grid grid-cols-3 gap-2">
@@ -124,6 +499,48 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
                 </div>
             </div>
 
+            // Lorenz Curve Panel
+            <div class="mt-8 bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
+                <div class="flex items-center justify-between mb-4">
+                    <div class="flex items-center">
+                        <div class="text-2xl mr-3">{"📉"}</div>
+                        <div>
+                            <h4 class="text-xl font-bold text-gray-900 dark:text-white">{"Wealth Concentration (Lorenz Curve)"}</h4>
+                            <p class="text-sm text-gray-600 dark:text-gray-300">{"Cumulative share of supply held by the poorest X% of addresses"}</p>
+                        </div>
+                    </div>
+                    <div class="flex items-center gap-4">
+                        <div class="text-right">
+                            <div class="text-2xl font-bold text-purple-600 dark:text-purple-400">{format!("{:.3}", gini_coefficient)}</div>
+                            <div class="text-xs text-gray-600 dark:text-gray-300">{"Gini coefficient"}</div>
+                        </div>
+                        <div class="flex gap-2">
+                            <button
+                                onclick={on_export_lorenz_svg}
+                                class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            >
+                                {"⬇ SVG"}
+                            </button>
+                            <button
+                                onclick={on_export_lorenz_png}
+                                class="px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            >
+                                {"⬇ PNG"}
+                            </button>
+                        </div>
+                    </div>
+                </div>
+                <div class="h-64 relative bg-gray-50 dark:bg-gray-700 rounded-lg overflow-hidden">
+                    <canvas
+                        ref={lorenz_canvas_ref}
+                        width="400"
+                        height="300"
+                        class="w-full h-full object-contain"
+                        id="lorenz-chart"
+                    />
+                </div>
+            </div>
+
             // Distribution Insights Section
             <div class="mt-8 grid grid-cols-1 md:grid-cols-2 gap-6">
                 <div class="bg-gradient-to-r from-purple-50 to-indigo-50 dark:from-purple-900/20 dark:to-indigo-900/20 rounded-xl p-6 border border-purple-200 dark:border-purple-800">
@@ -172,20 +589,56 @@ pub fn statistics_chart(props: &StatisticsChartProps) -> Html {
     }
 }
 
-/// Draw the supply concentration chart as an enhanced pie chart
+/// Output of a draw pass that the component caches for pointer hit-testing
+struct SupplyChartResult {
+    transform: PieTransform,
+    slices: Vec<SupplySlice>,
+}
+
+/// Draw the supply concentration chart as an enhanced pie chart onto a live canvas
 fn draw_supply_concentration_chart(
     canvas: HtmlCanvasElement,
     distribution: &BitcoinDistribution,
-) -> Result<(), Box<dyn std::error::Error>> {
+    highlighted: Option<&'static str>,
+) -> Result<SupplyChartResult, Box<dyn std::error::Error>> {
     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
     let root = backend.into_drawing_area();
+    render_supply_concentration_chart(root, distribution, highlighted)
+}
+
+/// Render the supply concentration chart to an in-memory SVG document, for the "Export SVG" button
+fn render_supply_concentration_chart_svg(
+    distribution: &BitcoinDistribution,
+    highlighted: Option<&'static str>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut svg_content = String::new();
+    {
+        let backend = SVGBackend::with_string(&mut svg_content, (CHART_WIDTH, CHART_HEIGHT));
+        let root = backend.into_drawing_area();
+        render_supply_concentration_chart(root, distribution, highlighted)?;
+    }
+    Ok(svg_content)
+}
+
+/// Draw the supply concentration chart as an enhanced pie chart. `highlighted` names the slice
+/// (if any) to redraw brighter with an outline, as tracked by the component's hover state. Generic
+/// over `DrawingBackend` so the same plotting logic can target a live canvas or an in-memory SVG.
+fn render_supply_concentration_chart<DB>(
+    root: DrawingArea<DB, Shift>,
+    distribution: &BitcoinDistribution,
+    highlighted: Option<&'static str>,
+) -> Result<SupplyChartResult, Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
     let theme = MempoolChartTheme::new();
-    
+
     root.fill(&theme.background)?;
 
     // Calculate dynamic supply concentration based on actual data
     let mut supply_ranges = Vec::new();
-    
+
     // Calculate supply by percentiles more accurately
     let mut ranges_sorted = distribution.ranges.clone();
     ranges_sorted.sort_by(|a, b| b.min_btc.partial_cmp(&a.min_btc).unwrap());
@@ -193,15 +646,15 @@ fn draw_supply_concentration_chart(
     let mut top_10_percent = 0.0;
     let mut top_50_percent = 0.0;
     let mut remaining = 0.0;
-    
+
     let mut cumulative_addresses = 0u64;
-    
+
     for range in &ranges_sorted {
         let range_supply = ((range.max_btc + range.min_btc) / 2.0) * range.address_count as f64;
         cumulative_addresses += range.address_count;
-        
+
         let address_percentile = (cumulative_addresses as f64 / distribution.total_addresses as f64) * 100.0;
-        
+
         if address_percentile <= 1.0 {
             top_1_percent += range_supply;
         } else if address_percentile <= 10.0 {
@@ -227,12 +680,23 @@ fn draw_supply_concentration_chart(
         .margin(20)
         .build_cartesian_2d(-1.3..1.3, -1.3..1.3)?;
 
+    let (plot_x_range, plot_y_range) = chart.plotting_area().get_pixel_range();
+    let transform = PieTransform {
+        plot_left: plot_x_range.start,
+        plot_right: plot_x_range.end,
+        plot_top: plot_y_range.start,
+        plot_bottom: plot_y_range.end,
+    };
+
     let total_displayed = supply_ranges.iter().map(|(_, value, _)| *value).sum::<f64>();
     let mut start_angle = 0.0;
+    let mut slices = Vec::with_capacity(supply_ranges.len());
 
     for (label, value, color) in supply_ranges {
         let percentage = (value / total_displayed) * 100.0;
         let angle = (value / total_displayed) * 360.0;
+        let is_highlighted = highlighted == Some(label);
+        let slice_color = if is_highlighted { color.mix(1.0) } else { color.mix(0.85) };
 
         // Draw pie slice with smoother curves
         let center = (0.0, 0.0);
@@ -245,10 +709,14 @@ fn draw_supply_concentration_chart(
             points.push((radius * rad.cos(), radius * rad.sin()));
         }
 
-        chart.draw_series(std::iter::once(Polygon::new(
-            points,
-            color.mix(0.85).filled(),
-        )))?;
+        chart.draw_series(std::iter::once(Polygon::new(points.clone(), slice_color.filled())))?;
+
+        if is_highlighted {
+            chart.draw_series(std::iter::once(PathElement::new(
+                points,
+                theme.text_primary.stroke_width(2),
+            )))?;
+        }
 
         // Enhanced labels with percentage
         if angle > 20.0 { // Only show labels for significant slices
@@ -266,32 +734,113 @@ fn draw_supply_concentration_chart(
             )))?;
         }
 
+        slices.push(SupplySlice {
+            label,
+            start_angle,
+            end_angle: start_angle + angle,
+            btc_amount: value,
+            percentage,
+        });
+
         start_angle += angle;
     }
 
-    Ok(())
+    Ok(SupplyChartResult { transform, slices })
+}
+
+/// Output of a draw pass that the component caches for pointer hit-testing
+struct AddressChartResult {
+    transform: BarTransform,
+    bars: Vec<AddressBar>,
+}
+
+/// Label an address range as `"min-max"` BTC, or `"min+"` for an open-ended top range
+fn address_range_label(range: &crate::types::bitcoin::WealthRange) -> String {
+    if range.max_btc == f64::INFINITY {
+        format!("{}+", format_large_number(range.min_btc))
+    } else if range.max_btc >= 1.0 {
+        format!("{}-{}", format_large_number(range.min_btc), format_large_number(range.max_btc))
+    } else {
+        format!("{:.3}-{:.3}", range.min_btc, range.max_btc)
+    }
 }
 
-/// Draw the address distribution chart as an enhanced bar chart
+/// One evenly-spaced, on-brand color per address range bar, so charts with more bars than the
+/// old fixed 8-color list never repeat or clash
+fn address_bar_colors(theme: &MempoolChartTheme, count: usize) -> Vec<RGBColor> {
+    theme.palette(count)
+}
+
+fn address_bar_geometry(range: &crate::types::bitcoin::WealthRange, index: usize) -> AddressBar {
+    AddressBar {
+        label: address_range_label(range),
+        address_count: range.address_count,
+        btc_amount: (range.max_btc + range.min_btc) / 2.0 * range.address_count as f64,
+        min_index: index as f64,
+        max_index: (index + 1) as f64,
+    }
+}
+
+/// Draw the address distribution chart as an enhanced bar chart onto a live canvas. `highlighted`
+/// names the bar (if any) to redraw brighter with an outline, as tracked by the component's hover
+/// state.
 fn draw_address_distribution_chart(
     canvas: HtmlCanvasElement,
     distribution: &BitcoinDistribution,
-) -> Result<(), Box<dyn std::error::Error>> {
+    highlighted: Option<&str>,
+    scale: AddressChartScale,
+) -> Result<AddressChartResult, Box<dyn std::error::Error>> {
     let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
     let root = backend.into_drawing_area();
+    match scale {
+        AddressChartScale::Linear => render_address_distribution_chart_linear(root, distribution, highlighted),
+        AddressChartScale::Log => render_address_distribution_chart_log(root, distribution, highlighted),
+    }
+}
+
+/// Render the address distribution chart to an in-memory SVG document, for the "Export SVG" button
+fn render_address_distribution_chart_svg(
+    distribution: &BitcoinDistribution,
+    highlighted: Option<&str>,
+    scale: AddressChartScale,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut svg_content = String::new();
+    {
+        let backend = SVGBackend::with_string(&mut svg_content, (CHART_WIDTH, CHART_HEIGHT));
+        let root = backend.into_drawing_area();
+        match scale {
+            AddressChartScale::Linear => render_address_distribution_chart_linear(root, distribution, highlighted)?,
+            AddressChartScale::Log => render_address_distribution_chart_log(root, distribution, highlighted)?,
+        };
+    }
+    Ok(svg_content)
+}
+
+fn render_address_distribution_chart_linear<DB>(
+    root: DrawingArea<DB, Shift>,
+    distribution: &BitcoinDistribution,
+    highlighted: Option<&str>,
+) -> Result<AddressChartResult, Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
     let theme = MempoolChartTheme::new();
-    
+
     root.fill(&theme.background)?;
 
     // Select meaningful ranges for display
     let mut ranges = distribution.ranges.clone();
     ranges.sort_by(|a, b| b.address_count.cmp(&a.address_count));
-    
+
     // Take top 8 ranges for better readability
     ranges.truncate(8);
 
     if ranges.is_empty() {
-        return Ok(());
+        return Ok(AddressChartResult {
+            transform: BarTransform { plot_left: 0, plot_right: 0, bar_count: 0 },
+            bars: Vec::new(),
+        });
     }
 
     let max_count = ranges.iter().map(|r| r.address_count).max().unwrap();
@@ -303,20 +852,20 @@ fn draw_address_distribution_chart(
         .y_label_area_size(80)
         .build_cartesian_2d(0..ranges.len(), 0..max_count)?;
 
+    let (plot_x_range, _) = chart.plotting_area().get_pixel_range();
+    let transform = BarTransform {
+        plot_left: plot_x_range.start,
+        plot_right: plot_x_range.end,
+        bar_count: ranges.len(),
+    };
+
     chart
         .configure_mesh()
         .x_desc("BTC Range")
         .y_desc("Address Count")
         .x_label_formatter(&|x| {
             if *x < ranges.len() {
-                let range = &ranges[*x];
-                if range.max_btc == f64::INFINITY {
-                    format!("{}+", format_large_number(range.min_btc))
-                } else if range.max_btc >= 1.0 {
-                    format!("{}-{}", format_large_number(range.min_btc), format_large_number(range.max_btc))
-                } else {
-                    format!("{:.3}-{:.3}", range.min_btc, range.max_btc)
-                }
+                address_range_label(&ranges[*x])
             } else {
                 String::new()
             }
@@ -327,23 +876,32 @@ fn draw_address_distribution_chart(
         .bold_line_style(&theme.border_primary)
         .draw()?;
 
-    // Enhanced gradient colors
-    let colors = vec![
-        theme.bitcoin_orange,
-        theme.chart_secondary,
-        theme.chart_accent,
-        RGBColor(139, 69, 19),  // Brown
-        RGBColor(75, 0, 130),   // Indigo
-        RGBColor(255, 20, 147), // Deep pink
-        RGBColor(0, 100, 0),    // Dark green
-        RGBColor(255, 140, 0),  // Dark orange
-    ];
+    let colors = address_bar_colors(&theme, ranges.len());
+    let mut bars = Vec::with_capacity(ranges.len());
+
+    for (i, range) in ranges.iter().enumerate() {
+        let bar = address_bar_geometry(range, i);
+        let color = colors.get(i).unwrap_or(&theme.bitcoin_orange);
+        let is_highlighted = highlighted == Some(bar.label.as_str());
+        let bar_color = if is_highlighted { color.mix(1.0) } else { color.mix(0.8) };
+
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [(i, 0), (i, range.address_count)],
+            bar_color.filled(),
+        )))?;
+
+        if is_highlighted {
+            chart.draw_series(std::iter::once(Rectangle::new(
+                [(i, 0), (i, range.address_count)],
+                theme.text_primary.stroke_width(2),
+            )))?;
+        }
+
+        bars.push(bar);
+    }
 
     chart
-        .draw_series(ranges.iter().enumerate().map(|(i, range)| {
-            let color = colors.get(i % colors.len()).unwrap_or(&theme.bitcoin_orange);
-            Rectangle::new([(i, 0), (i, range.address_count)], color.mix(0.8).filled())
-        }))?
+        .draw_series(std::iter::empty::<Rectangle<(usize, u64)>>())?
         .label("Addresses")
         .legend(|(x, y)| Rectangle::new([(x, y), (x + 15, y + 10)], theme.bitcoin_orange.filled()));
 
@@ -354,5 +912,281 @@ fn draw_address_distribution_chart(
         .label_font(theme.create_text_style(12))
         .draw()?;
 
+    Ok(AddressChartResult { transform, bars })
+}
+
+/// Same chart in log-scale mode: every populated range is shown (no top-8 truncation) and the
+/// y-axis uses `LogScalable` so long-tail ranges orders of magnitude apart stay readable. Ranges
+/// with a zero address count are skipped since `log(0)` is undefined.
+fn render_address_distribution_chart_log<DB>(
+    root: DrawingArea<DB, Shift>,
+    distribution: &BitcoinDistribution,
+    highlighted: Option<&str>,
+) -> Result<AddressChartResult, Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let theme = MempoolChartTheme::new();
+
+    root.fill(&theme.background)?;
+
+    let mut ranges: Vec<_> = distribution
+        .ranges
+        .iter()
+        .filter(|range| range.address_count > 0)
+        .cloned()
+        .collect();
+    ranges.sort_by(|a, b| b.address_count.cmp(&a.address_count));
+
+    if ranges.is_empty() {
+        return Ok(AddressChartResult {
+            transform: BarTransform { plot_left: 0, plot_right: 0, bar_count: 0 },
+            bars: Vec::new(),
+        });
+    }
+
+    let max_count = ranges.iter().map(|r| r.address_count).max().unwrap();
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Top Bitcoin Address Ranges (log scale)", theme.create_text_style(18))
+        .margin(20)
+        .x_label_area_size(70)
+        .y_label_area_size(80)
+        .build_cartesian_2d(0..ranges.len(), (1u64..max_count).log_scale())?;
+
+    let (plot_x_range, _) = chart.plotting_area().get_pixel_range();
+    let transform = BarTransform {
+        plot_left: plot_x_range.start,
+        plot_right: plot_x_range.end,
+        bar_count: ranges.len(),
+    };
+
+    chart
+        .configure_mesh()
+        .x_desc("BTC Range")
+        .y_desc("Address Count (log scale)")
+        .x_label_formatter(&|x| {
+            if *x < ranges.len() {
+                address_range_label(&ranges[*x])
+            } else {
+                String::new()
+            }
+        })
+        .y_label_formatter(&|y| format_large_number(*y as f64))
+        .label_style(theme.create_text_style(10))
+        .axis_style(&theme.grid_color)
+        .bold_line_style(&theme.border_primary)
+        .draw()?;
+
+    let colors = address_bar_colors(&theme, ranges.len());
+    let mut bars = Vec::with_capacity(ranges.len());
+
+    for (i, range) in ranges.iter().enumerate() {
+        let bar = address_bar_geometry(range, i);
+        let color = colors.get(i).unwrap_or(&theme.bitcoin_orange);
+        let is_highlighted = highlighted == Some(bar.label.as_str());
+        let bar_color = if is_highlighted { color.mix(1.0) } else { color.mix(0.8) };
+
+        chart.draw_series(std::iter::once(Rectangle::new(
+            [(i, 1), (i, range.address_count)],
+            bar_color.filled(),
+        )))?;
+
+        if is_highlighted {
+            chart.draw_series(std::iter::once(Rectangle::new(
+                [(i, 1), (i, range.address_count)],
+                theme.text_primary.stroke_width(2),
+            )))?;
+        }
+
+        bars.push(bar);
+    }
+
+    chart
+        .draw_series(std::iter::empty::<Rectangle<(usize, u64)>>())?
+        .label("Addresses")
+        .legend(|(x, y)| Rectangle::new([(x, y), (x + 15, y + 10)], theme.bitcoin_orange.filled()));
+
+    chart
+        .configure_series_labels()
+        .background_style(&theme.card_background.mix(0.9))
+        .border_style(&theme.border_primary)
+        .label_font(theme.create_text_style(12))
+        .draw()?;
+
+    Ok(AddressChartResult { transform, bars })
+}
+
+/// Build the Lorenz curve points for `distribution` and the Gini coefficient they imply.
+///
+/// Ranges are sorted ascending by representative balance (their midpoint, or `min_btc` for an
+/// open-ended top range where `max_btc == INFINITY`), then walked to accumulate
+/// `(cumulative_addresses / total_addresses, cumulative_supply / total_supply)` points starting
+/// from `(0, 0)`. `B` is the trapezoid area under the curve, and the Gini coefficient is
+/// `Gini = 1 - 2B`, clamped to `[0, 1]`.
+fn lorenz_curve_points(distribution: &BitcoinDistribution) -> (Vec<(f64, f64)>, f64) {
+    let mut points = vec![(0.0, 0.0)];
+
+    if distribution.total_addresses == 0 {
+        return (points, 0.0);
+    }
+
+    let representative = |range: &crate::types::bitcoin::WealthRange| {
+        if range.max_btc == f64::INFINITY {
+            range.min_btc
+        } else {
+            (range.min_btc + range.max_btc) / 2.0
+        }
+    };
+
+    let mut ranges_sorted: Vec<_> = distribution
+        .ranges
+        .iter()
+        .filter(|range| range.address_count > 0)
+        .collect();
+    ranges_sorted.sort_by(|a, b| representative(a).partial_cmp(&representative(b)).unwrap());
+
+    let total_supply: f64 = ranges_sorted
+        .iter()
+        .map(|range| representative(range) * range.address_count as f64)
+        .sum();
+
+    if total_supply <= 0.0 {
+        return (points, 0.0);
+    }
+
+    let mut cumulative_addresses = 0u64;
+    let mut cumulative_supply = 0.0;
+
+    for range in ranges_sorted {
+        cumulative_addresses += range.address_count;
+        cumulative_supply += representative(range) * range.address_count as f64;
+        points.push((
+            cumulative_addresses as f64 / distribution.total_addresses as f64,
+            cumulative_supply / total_supply,
+        ));
+    }
+
+    let mut area = 0.0;
+    for pair in points.windows(2) {
+        let (x0, y0) = pair[0];
+        let (x1, y1) = pair[1];
+        area += (x1 - x0) * (y1 + y0) / 2.0;
+    }
+    let gini = (1.0 - 2.0 * area).clamp(0.0, 1.0);
+
+    (points, gini)
+}
+
+/// Draw the Lorenz curve against the 45° line of perfect equality, onto a live canvas
+fn draw_lorenz_curve_chart(
+    canvas: HtmlCanvasElement,
+    points: &[(f64, f64)],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+    render_lorenz_curve_chart(root, points)
+}
+
+/// Render the Lorenz curve to an in-memory SVG document, for the "Export SVG" button
+fn render_lorenz_curve_chart_svg(points: &[(f64, f64)]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut svg_content = String::new();
+    {
+        let backend = SVGBackend::with_string(&mut svg_content, (CHART_WIDTH, CHART_HEIGHT));
+        let root = backend.into_drawing_area();
+        render_lorenz_curve_chart(root, points)?;
+    }
+    Ok(svg_content)
+}
+
+/// Plot the Lorenz curve against the 45° line of perfect equality. Generic over `DrawingBackend`
+/// so the same plotting logic can target a live canvas or an in-memory SVG.
+fn render_lorenz_curve_chart<DB>(
+    root: DrawingArea<DB, Shift>,
+    points: &[(f64, f64)],
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let theme = MempoolChartTheme::new();
+
+    root.fill(&theme.background)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Lorenz Curve", theme.create_text_style(18))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Cumulative share of addresses")
+        .y_desc("Cumulative share of supply")
+        .label_style(theme.create_text_style(10))
+        .axis_style(&theme.grid_color)
+        .draw()?;
+
+    // Line of perfect equality
+    chart.draw_series(std::iter::once(PathElement::new(
+        vec![(0.0, 0.0), (1.0, 1.0)],
+        theme.border_secondary.stroke_width(1),
+    )))?;
+
+    // Area under the Lorenz curve
+    let mut area_points = points.to_vec();
+    area_points.push((1.0, 0.0));
+    chart.draw_series(std::iter::once(Polygon::new(
+        area_points,
+        theme.bitcoin_orange.mix(0.25).filled(),
+    )))?;
+
+    // The Lorenz curve itself
+    chart.draw_series(std::iter::once(PathElement::new(
+        points.to_vec(),
+        theme.bitcoin_orange.stroke_width(3),
+    )))?;
+
+    Ok(())
+}
+
+/// Wrap `content` in a Blob of the given MIME type and trigger a browser download via an
+/// object URL and a synthetic `<a download>` click
+fn trigger_blob_download(content: &str, filename: &str, mime_type: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::new();
+    parts.push(&JsValue::from_str(content));
+
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let result = trigger_anchor_download(&url, filename);
+    web_sys::Url::revoke_object_url(&url)?;
+
+    result
+}
+
+/// Trigger a browser download directly from a `data:` URL (e.g. `canvas.to_data_url`)
+fn trigger_data_url_download(data_url: &str, filename: &str) -> Result<(), JsValue> {
+    trigger_anchor_download(data_url, filename)
+}
+
+fn trigger_anchor_download(href: &str, filename: &str) -> Result<(), JsValue> {
+    let document = window()
+        .ok_or("No window available")?
+        .document()
+        .ok_or("No document available")?;
+
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()
+        .map_err(|_| JsValue::from_str("Failed to create anchor element"))?;
+    anchor.set_href(href);
+    anchor.set_download(filename);
+    anchor.click();
+
     Ok(())
 }
@@ -0,0 +1,149 @@
+use crate::utils::chart_theme::MempoolChartTheme;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+/// A single point on the trendline: a pre-formatted period label (e.g. "7d ago", "Today") paired
+/// with the percentile value at that point. Labels are plain strings rather than a typed date,
+/// matching the rest of the codebase (no date/time crate is used anywhere else here either).
+pub type TrendPoint = (String, f64);
+
+#[derive(Properties, PartialEq)]
+pub struct TrendlineChartProps {
+    pub history: Vec<TrendPoint>,
+    #[prop_or_default]
+    pub current_percentile: Option<f64>,
+}
+
+/// Builds a flat history seeded from the user's current percentile, for when
+/// `PercentileHistoryStorage` has no recorded snapshots yet for the entered amount (e.g. the
+/// first time it's ever calculated). Every point sits at today's value - this keeps the
+/// trendline panel's layout stable instead of hiding it until real history has accumulated.
+pub fn flat_placeholder_history(current_percentile: f64, points: usize) -> Vec<TrendPoint> {
+    let labels = ["7d ago", "6d ago", "5d ago", "4d ago", "3d ago", "2d ago", "1d ago", "Today"];
+    let skip = labels.len().saturating_sub(points);
+
+    labels
+        .iter()
+        .skip(skip)
+        .map(|label| (label.to_string(), current_percentile))
+        .collect()
+}
+
+/// Shows how a holder's percentile standing has moved over time, as a filled area/line chart
+/// with the period label on the x-axis. Placed next to "Comparison Insights" so a single snapshot
+/// result is paired with a sense of trajectory, mirroring personal-finance balance-history panels.
+#[function_component(TrendlineChart)]
+pub fn trendline_chart(props: &TrendlineChartProps) -> Html {
+    let canvas_ref = use_node_ref();
+
+    let history = if props.history.is_empty() {
+        flat_placeholder_history(props.current_percentile.unwrap_or(0.0), 8)
+    } else {
+        props.history.clone()
+    };
+
+    {
+        let canvas_ref = canvas_ref.clone();
+        let history = history.clone();
+
+        use_effect_with(history.clone(), move |_| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                let _ = draw_trendline_chart(canvas, &history);
+            }
+            || ()
+        });
+    }
+
+    html! {
+        <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
+            <div class="mb-8">
+                <div class="flex items-center mb-4">
+                    <div class="text-3xl mr-3">{"📉"}</div>
+                    <div>
+                        <h3 class="text-3xl font-bold text-gray-900 dark:text-white">{"Percentile Trend"}</h3>
+                        <p class="text-lg text-gray-600 dark:text-gray-300">{"How your standing has moved over time"}</p>
+                    </div>
+                </div>
+            </div>
+
+            <div class="relative bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
+                <canvas
+                    ref={canvas_ref}
+                    width="500"
+                    height="300"
+                    class="w-full h-auto rounded-lg"
+                    style="max-width: 100%; height: auto;"
+                />
+            </div>
+        </div>
+    }
+}
+
+fn draw_trendline_chart(
+    canvas: HtmlCanvasElement,
+    history: &[TrendPoint],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+
+    let theme = MempoolChartTheme::current();
+    root.fill(&theme.background)?;
+
+    if history.is_empty() {
+        return Ok(());
+    }
+
+    let max_value = history
+        .iter()
+        .map(|(_, value)| *value)
+        .fold(0.0_f64, f64::max)
+        .max(1.0);
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Percentile over time", theme.create_text_style(18))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0..history.len().saturating_sub(1).max(1), 0.0..(max_value * 1.1))?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Period")
+        .y_desc("Percentile")
+        .x_label_formatter(&|index| {
+            history
+                .get(*index)
+                .map(|(label, _)| label.clone())
+                .unwrap_or_default()
+        })
+        .y_label_formatter(&|y| format!("{:.0}%", y))
+        .label_style(theme.create_secondary_text_style(12))
+        .axis_style(&theme.grid_color)
+        .draw()?;
+
+    let points: Vec<(usize, f64)> = history
+        .iter()
+        .enumerate()
+        .map(|(index, (_, value))| (index, *value))
+        .collect();
+
+    // Filled area under the trendline, down to the x-axis
+    chart.draw_series(std::iter::once(Polygon::new(
+        points
+            .iter()
+            .map(|(x, y)| (*x, *y))
+            .chain(points.iter().rev().map(|(x, _)| (*x, 0.0)))
+            .collect::<Vec<_>>(),
+        theme.bitcoin_orange.mix(0.15),
+    )))?;
+
+    chart.draw_series(std::iter::once(PathElement::new(
+        points,
+        theme.bitcoin_orange.stroke_width(3),
+    )))?;
+
+    root.present()?;
+    Ok(())
+}
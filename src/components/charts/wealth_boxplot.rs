@@ -0,0 +1,190 @@
+use crate::types::bitcoin::BitcoinDistribution;
+use crate::utils::chart_theme::MempoolChartTheme;
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct WealthBoxPlotProps {
+    pub distribution: BitcoinDistribution,
+    #[prop_or_default]
+    pub user_percentile: Option<f64>,
+}
+
+#[function_component(WealthBoxPlot)]
+pub fn wealth_box_plot(props: &WealthBoxPlotProps) -> Html {
+    let canvas_ref = use_node_ref();
+
+    // Effect to draw the chart when data changes
+    {
+        let canvas_ref = canvas_ref.clone();
+        let distribution = props.distribution.clone();
+        let user_percentile = props.user_percentile;
+
+        use_effect_with((distribution.clone(), user_percentile), move |_| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                let _ = draw_wealth_box_plot(canvas, &distribution, user_percentile);
+            }
+            || ()
+        });
+    }
+
+    html! {
+        <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
+            <div class="mb-8">
+                <div class="flex items-center mb-4">
+                    <div class="text-3xl mr-3">{"📦"}</div>
+                    <div>
+                        <h3 class="text-3xl font-bold text-gray-900 dark:text-white">{"Wealth Tier Spread"}</h3>
+                        <p class="text-lg text-gray-600 dark:text-gray-300">{"Five-number summary of Bitcoin holdings within each wealth category"}</p>
+                    </div>
+                </div>
+            </div>
+
+            <div class="relative bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg hover:shadow-xl transition-shadow duration-300">
+                <canvas
+                    ref={canvas_ref}
+                    width="800"
+                    height="400"
+                    class="w-full h-auto rounded-lg"
+                    style="max-width: 100%; height: auto;"
+                />
+            </div>
+        </div>
+    }
+}
+
+/// Wealth tiers as (name, start percentile, end percentile), matching
+/// `comparison_chart::get_wealth_category_ranges`
+fn wealth_tiers() -> Vec<(&'static str, f64, f64)> {
+    vec![
+        ("Shrimp", 0.0, 50.0),
+        ("Crab", 50.0, 75.0),
+        ("Fish", 75.0, 90.0),
+        ("Dolphin", 90.0, 95.0),
+        ("Shark", 95.0, 99.0),
+        ("Whale", 99.0, 100.0),
+    ]
+}
+
+/// Invert the CDF to find the BTC amount held at `target_percentile`, the same way
+/// `comparison_chart::calculate_percentile_amount` does. The landing range's upper bound can be
+/// `f64::INFINITY` for the open-ended top `WealthRange` every real distribution has (e.g. at
+/// `target_percentile == 100.0`); interpolating into that bound would return `INFINITY` and
+/// poison every downstream quartile/axis computation, so that range's `min_btc` is used as its
+/// representative value instead, matching `statistics_chart::lorenz_curve_points`.
+fn calculate_percentile_amount(
+    distribution: &BitcoinDistribution,
+    target_percentile: f64,
+) -> Option<f64> {
+    let mut cumulative_addresses = 0u64;
+    let target_count = (distribution.total_addresses as f64 * target_percentile / 100.0) as u64;
+
+    for range in &distribution.ranges {
+        cumulative_addresses += range.address_count;
+        if cumulative_addresses >= target_count {
+            if range.max_btc.is_infinite() {
+                return Some(range.min_btc);
+            }
+
+            let range_position = (target_count - (cumulative_addresses - range.address_count))
+                as f64
+                / range.address_count as f64;
+            return Some(range.min_btc + (range.max_btc - range.min_btc) * range_position);
+        }
+    }
+
+    None
+}
+
+/// Box-plot summary for a tier's percentile span, built from the BTC amounts found by inverting
+/// the CDF at the span's lower bound, quartiles and upper bound
+fn tier_quartiles(distribution: &BitcoinDistribution, start: f64, end: f64) -> Option<Quartiles> {
+    let span = end - start;
+    let values = [
+        calculate_percentile_amount(distribution, start)?,
+        calculate_percentile_amount(distribution, start + span * 0.25)?,
+        calculate_percentile_amount(distribution, start + span * 0.5)?,
+        calculate_percentile_amount(distribution, start + span * 0.75)?,
+        calculate_percentile_amount(distribution, end)?,
+    ];
+
+    // `Quartiles::new` takes the linear-interpolated 25th/50th/75th percentiles of these five
+    // sorted values, which are exactly `values[1..=3]`, so the box and median line are the tier's
+    // quartile amounts. Its whisker ends are the 1.5 * IQR fences (Q1 - 1.5 * IQR and
+    // Q3 + 1.5 * IQR), not `values[0]` and `values[4]`: the whiskers do not show the span's lower
+    // and upper bound amounts.
+    Some(Quartiles::new(&values))
+}
+
+fn draw_wealth_box_plot(
+    canvas: HtmlCanvasElement,
+    distribution: &BitcoinDistribution,
+    user_percentile: Option<f64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+
+    let theme = MempoolChartTheme::current();
+    root.fill(&theme.background)?;
+
+    let tiers = wealth_tiers();
+    let tier_data: Vec<(&str, f64, f64, Quartiles)> = tiers
+        .into_iter()
+        .filter_map(|(name, start, end)| {
+            tier_quartiles(distribution, start, end).map(|q| (name, start, end, q))
+        })
+        .collect();
+
+    if tier_data.is_empty() {
+        return Ok(());
+    }
+
+    let min_btc = tier_data
+        .iter()
+        .map(|(_, _, _, q)| q.values()[0])
+        .fold(f64::INFINITY, |a, b| a.min(b as f64))
+        .max(0.0001);
+    let max_btc = tier_data
+        .iter()
+        .map(|(_, _, _, q)| q.values()[4])
+        .fold(0.0, |a, b| a.max(b as f64));
+
+    let tier_names: Vec<&str> = tier_data.iter().map(|(name, ..)| *name).collect();
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Wealth Tier Spread (BTC)", theme.create_text_style(18))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d(0..tier_data.len(), (min_btc..max_btc).log_scale())?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Wealth tier")
+        .y_desc("Bitcoin Amount (BTC)")
+        .x_label_formatter(&|x| tier_names.get(*x).map(|s| s.to_string()).unwrap_or_default())
+        .label_style(theme.create_secondary_text_style(12))
+        .axis_style(&theme.grid_color)
+        .draw()?;
+
+    for (index, (_, start, end, quartiles)) in tier_data.iter().enumerate() {
+        let highlighted = user_percentile
+            .map(|p| p >= *start && p <= *end)
+            .unwrap_or(false);
+
+        let color = if highlighted {
+            theme.bitcoin_orange
+        } else {
+            theme.chart_secondary
+        };
+
+        chart.draw_series(std::iter::once(
+            Boxplot::new_vertical(index, quartiles).style(color.stroke_width(2)),
+        ))?;
+    }
+
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,416 @@
+use crate::types::bitcoin::{BitcoinDistribution, WealthRange};
+use crate::utils::chart_theme::{format_bitcoin_amount, format_percentile, MempoolChartTheme};
+use plotters::prelude::*;
+use plotters_canvas::CanvasBackend;
+use web_sys::HtmlCanvasElement;
+use yew::prelude::*;
+
+/// The six named wealth tiers `MempoolChartTheme::get_wealth_colors` defines colors for, with
+/// the BTC-holding bounds (matching `WealthCategory::from_btc_amount`) used to fold each
+/// `WealthRange` bucket into one of them. `Dust` (< 0.001 BTC) and `Humpback` (>= 1000 BTC) are
+/// deliberately out of scope for this "shrimp-to-whale" view; dust ranges are dropped, and
+/// humpback-sized ranges are folded into `Whale`.
+const WEALTH_TIER_BOUNDS: [(&str, f64, f64); 6] = [
+    ("Shrimp", 0.001, 0.01),
+    ("Crab", 0.01, 0.1),
+    ("Fish", 0.1, 1.0),
+    ("Dolphin", 1.0, 10.0),
+    ("Shark", 10.0, 100.0),
+    ("Whale", 100.0, f64::INFINITY),
+];
+
+/// Which share of each tier's holdings sizes its tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreemapMetric {
+    TotalBtc,
+    AddressCount,
+}
+
+impl Default for TreemapMetric {
+    fn default() -> Self {
+        TreemapMetric::TotalBtc
+    }
+}
+
+/// How `WealthTreemap` lays out the tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreemapLayout {
+    Treemap,
+    Bar,
+}
+
+impl Default for TreemapLayout {
+    fn default() -> Self {
+        TreemapLayout::Treemap
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct WealthTreemapProps {
+    pub distribution: BitcoinDistribution,
+    #[prop_or_default]
+    pub user_amount: Option<f64>,
+}
+
+#[function_component(WealthTreemap)]
+pub fn wealth_treemap(props: &WealthTreemapProps) -> Html {
+    let canvas_ref = use_node_ref();
+    let metric = use_state(TreemapMetric::default);
+    let layout = use_state(TreemapLayout::default);
+
+    {
+        let canvas_ref = canvas_ref.clone();
+        let distribution = props.distribution.clone();
+        let user_amount = props.user_amount;
+        let metric = *metric;
+        let layout = *layout;
+
+        use_effect_with((distribution.clone(), user_amount, metric, layout), move |_| {
+            if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
+                let _ = draw_wealth_treemap(canvas, &distribution, user_amount, metric, layout);
+            }
+            || ()
+        });
+    }
+
+    let on_toggle_metric = {
+        let metric = metric.clone();
+        Callback::from(move |_: MouseEvent| {
+            metric.set(match *metric {
+                TreemapMetric::TotalBtc => TreemapMetric::AddressCount,
+                TreemapMetric::AddressCount => TreemapMetric::TotalBtc,
+            });
+        })
+    };
+
+    let on_toggle_layout = {
+        let layout = layout.clone();
+        Callback::from(move |_: MouseEvent| {
+            layout.set(match *layout {
+                TreemapLayout::Treemap => TreemapLayout::Bar,
+                TreemapLayout::Bar => TreemapLayout::Treemap,
+            });
+        })
+    };
+
+    html! {
+        <div class="bg-gradient-to-br from-white to-gray-50 dark:from-gray-800 dark:to-gray-900 rounded-xl shadow-xl p-8 border border-gray-200 dark:border-gray-700 transform hover:shadow-2xl transition-all duration-300">
+            <div class="mb-8">
+                <div class="flex items-center justify-between mb-4">
+                    <div class="flex items-center">
+                        <div class="text-3xl mr-3">{"🦐"}</div>
+                        <div>
+                            <h3 class="text-3xl font-bold text-gray-900 dark:text-white">{"Shrimp to Whale"}</h3>
+                            <p class="text-lg text-gray-600 dark:text-gray-300">{"Wealth tiers sized by their share of the network"}</p>
+                        </div>
+                    </div>
+
+                    <div class="flex items-center space-x-2">
+                        <button
+                            class="px-4 py-2 text-sm font-semibold rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-700 dark:text-gray-200 transition-colors duration-200"
+                            onclick={on_toggle_metric}
+                        >
+                            {match *metric {
+                                TreemapMetric::TotalBtc => "₿ By BTC Held",
+                                TreemapMetric::AddressCount => "🏦 By Addresses",
+                            }}
+                        </button>
+                        <button
+                            class="px-4 py-2 text-sm font-semibold rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-700 dark:text-gray-200 transition-colors duration-200"
+                            onclick={on_toggle_layout}
+                        >
+                            {match *layout {
+                                TreemapLayout::Treemap => "▭ Show Bars",
+                                TreemapLayout::Bar => "◱ Show Treemap",
+                            }}
+                        </button>
+                    </div>
+                </div>
+            </div>
+
+            <div class="relative bg-white dark:bg-gray-800 rounded-xl p-6 border border-gray-200 dark:border-gray-700 shadow-lg">
+                <canvas
+                    ref={canvas_ref}
+                    width="800"
+                    height="400"
+                    class="w-full h-auto rounded-lg"
+                    style="max-width: 100%; height: auto;"
+                />
+            </div>
+        </div>
+    }
+}
+
+/// One wealth tier aggregated across every `WealthRange` bucket that falls within its bounds
+struct TierTotals {
+    name: &'static str,
+    total_btc: f64,
+    address_count: u64,
+    /// Whether `user_amount` falls inside one of the `WealthRange`s folded into this tier
+    is_highlighted: bool,
+}
+
+fn tier_bounds_for(min_btc: f64) -> Option<(&'static str, f64, f64)> {
+    WEALTH_TIER_BOUNDS
+        .iter()
+        .find(|(_, lo, hi)| min_btc >= *lo && min_btc < *hi)
+        .copied()
+}
+
+/// Fold the distribution's `WealthRange` buckets into the six named wealth tiers, dropping
+/// dust-sized ranges and summing any ranges above the `Whale` floor into `Whale`.
+fn aggregate_tiers(ranges: &[WealthRange], user_amount: Option<f64>) -> Vec<TierTotals> {
+    let mut totals: Vec<TierTotals> = WEALTH_TIER_BOUNDS
+        .iter()
+        .map(|(name, ..)| TierTotals {
+            name,
+            total_btc: 0.0,
+            address_count: 0,
+            is_highlighted: false,
+        })
+        .collect();
+
+    for range in ranges {
+        let Some((name, ..)) = tier_bounds_for(range.min_btc) else {
+            continue;
+        };
+        let Some(tier) = totals.iter_mut().find(|t| t.name == name) else {
+            continue;
+        };
+
+        tier.total_btc += range.total_btc;
+        tier.address_count += range.address_count;
+
+        if let Some(amount) = user_amount {
+            if amount >= range.min_btc && amount < range.max_btc {
+                tier.is_highlighted = true;
+            }
+        }
+    }
+
+    totals.retain(|t| t.total_btc > 0.0 || t.address_count > 0);
+    totals
+}
+
+/// An axis-aligned rectangle in the same units as the area passed to [`squarify`]
+#[derive(Debug, Clone, Copy)]
+struct TileRect {
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+}
+
+/// Lay out pre-sorted-descending `areas` (summing to `bounds.w * bounds.h`) via the squarified
+/// treemap algorithm (Bruls/Huizing/van Wijk "Squarified Treemaps"): greedily grow the current
+/// row while its worst width:height ratio keeps improving, then place the row along the shorter
+/// side of what's left and recurse on the remaining rectangle.
+fn squarify(areas: &[f64], bounds: TileRect) -> Vec<TileRect> {
+    let mut result = Vec::with_capacity(areas.len());
+    squarify_into(areas, &mut Vec::new(), bounds, &mut result);
+    result
+}
+
+fn squarify_into(remaining: &[f64], row: &mut Vec<f64>, bounds: TileRect, result: &mut Vec<TileRect>) {
+    let Some((&next, rest)) = remaining.split_first() else {
+        if !row.is_empty() {
+            place_row(row, bounds, result);
+        }
+        return;
+    };
+
+    let side = bounds.w.min(bounds.h);
+    let mut candidate = row.clone();
+    candidate.push(next);
+
+    if row.is_empty() || worst_ratio(&candidate, side) <= worst_ratio(row, side) {
+        row.push(next);
+        squarify_into(rest, row, bounds, result);
+    } else {
+        let leftover = place_row(row, bounds, result);
+        row.clear();
+        squarify_into(remaining, row, leftover, result);
+    }
+}
+
+/// The worst (largest) width:height ratio among tiles if `row` were laid out as a strip of
+/// thickness `row.sum() / side` along a side of length `side`
+fn worst_ratio(row: &[f64], side: f64) -> f64 {
+    if row.is_empty() || side <= 0.0 {
+        return f64::INFINITY;
+    }
+
+    let sum: f64 = row.iter().sum();
+    let thickness = sum / side;
+    if thickness <= 0.0 {
+        return f64::INFINITY;
+    }
+
+    row.iter()
+        .map(|&area| {
+            let length = area / thickness;
+            length.max(thickness) / length.min(thickness)
+        })
+        .fold(0.0_f64, f64::max)
+}
+
+/// Place `row`'s areas as a single strip along the shorter side of `bounds`, pushing the
+/// resulting tiles into `result`, and return the rectangle left over once the strip is removed.
+fn place_row(row: &[f64], bounds: TileRect, result: &mut Vec<TileRect>) -> TileRect {
+    let sum: f64 = row.iter().sum();
+
+    if bounds.w >= bounds.h {
+        let strip_w = if bounds.h > 0.0 { sum / bounds.h } else { 0.0 };
+        let mut y = bounds.y;
+        for &area in row {
+            let h = if strip_w > 0.0 { area / strip_w } else { 0.0 };
+            result.push(TileRect { x: bounds.x, y, w: strip_w, h });
+            y += h;
+        }
+        TileRect {
+            x: bounds.x + strip_w,
+            y: bounds.y,
+            w: (bounds.w - strip_w).max(0.0),
+            h: bounds.h,
+        }
+    } else {
+        let strip_h = if bounds.w > 0.0 { sum / bounds.w } else { 0.0 };
+        let mut x = bounds.x;
+        for &area in row {
+            let w = if strip_h > 0.0 { area / strip_h } else { 0.0 };
+            result.push(TileRect { x, y: bounds.y, w, h: strip_h });
+            x += w;
+        }
+        TileRect {
+            x: bounds.x,
+            y: bounds.y + strip_h,
+            w: bounds.w,
+            h: (bounds.h - strip_h).max(0.0),
+        }
+    }
+}
+
+fn draw_wealth_treemap(
+    canvas: HtmlCanvasElement,
+    distribution: &BitcoinDistribution,
+    user_amount: Option<f64>,
+    metric: TreemapMetric,
+    layout: TreemapLayout,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let backend = CanvasBackend::with_canvas_object(canvas).unwrap();
+    let root = backend.into_drawing_area();
+
+    let theme = MempoolChartTheme::current();
+    root.fill(&theme.background)?;
+
+    let tiers = aggregate_tiers(&distribution.ranges, user_amount);
+    if tiers.is_empty() {
+        return Err("no wealth tiers to plot".into());
+    }
+
+    let wealth_colors = theme.get_wealth_colors();
+    let tier_value = |t: &TierTotals| match metric {
+        TreemapMetric::TotalBtc => t.total_btc,
+        TreemapMetric::AddressCount => t.address_count as f64,
+    };
+    let total: f64 = tiers.iter().map(tier_value).sum();
+    if total <= 0.0 {
+        return Err("wealth tiers have no share to plot".into());
+    }
+
+    let root = root.margin(10, 10, 10, 10);
+    let (plot_w, plot_h) = root.dim_in_pixel();
+    let plot_w = plot_w as f64;
+    let plot_h = plot_h as f64;
+
+    let color_for = |name: &str| {
+        wealth_colors
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, color)| *color)
+            .unwrap_or(theme.chart_secondary)
+    };
+
+    match layout {
+        TreemapLayout::Treemap => {
+            let mut ordered: Vec<&TierTotals> = tiers.iter().collect();
+            ordered.sort_by(|a, b| tier_value(b).partial_cmp(&tier_value(a)).unwrap());
+
+            let areas: Vec<f64> = ordered
+                .iter()
+                .map(|t| tier_value(t) / total * plot_w * plot_h)
+                .collect();
+            let rects = squarify(&areas, TileRect { x: 0.0, y: 0.0, w: plot_w, h: plot_h });
+
+            for (tier, rect) in ordered.iter().zip(rects.iter()) {
+                let color = color_for(tier.name);
+                let top_left = (rect.x.round() as i32, rect.y.round() as i32);
+                let bottom_right = ((rect.x + rect.w).round() as i32, (rect.y + rect.h).round() as i32);
+
+                root.draw(&Rectangle::new([top_left, bottom_right], color.mix(0.85).filled()))?;
+                if tier.is_highlighted {
+                    root.draw(&Rectangle::new(
+                        [top_left, bottom_right],
+                        theme.bitcoin_orange.stroke_width(3),
+                    ))?;
+                }
+
+                if rect.w > 60.0 && rect.h > 30.0 {
+                    let label = format!(
+                        "{}\n{}",
+                        tier.name,
+                        match metric {
+                            TreemapMetric::TotalBtc => format_bitcoin_amount(tier.total_btc),
+                            TreemapMetric::AddressCount =>
+                                format!("{} addrs", tier.address_count),
+                        }
+                    );
+                    for (line_index, line) in label.lines().enumerate() {
+                        root.draw(&Text::new(
+                            line.to_string(),
+                            (top_left.0 + 8, top_left.1 + 8 + line_index as i32 * 16),
+                            ("Inter", 13).into_font().color(&theme.card_background),
+                        ))?;
+                    }
+                    root.draw(&Text::new(
+                        format!("{}", format_percentile(tier_value(tier) / total * 100.0)),
+                        (top_left.0 + 8, top_left.1 + rect.h as i32 - 20),
+                        ("Inter", 11).into_font().color(&theme.card_background),
+                    ))?;
+                }
+            }
+        }
+        TreemapLayout::Bar => {
+            let mut x = 0.0;
+            for tier in &tiers {
+                let share = tier_value(tier) / total;
+                let w = share * plot_w;
+                let color = color_for(tier.name);
+
+                let top_left = (x.round() as i32, 0);
+                let bottom_right = ((x + w).round() as i32, plot_h.round() as i32);
+
+                root.draw(&Rectangle::new([top_left, bottom_right], color.mix(0.85).filled()))?;
+                if tier.is_highlighted {
+                    root.draw(&Rectangle::new(
+                        [top_left, bottom_right],
+                        theme.bitcoin_orange.stroke_width(3),
+                    ))?;
+                }
+
+                if w > 50.0 {
+                    root.draw(&Text::new(
+                        format!("{} {}", tier.name, format_percentile(share * 100.0)),
+                        (top_left.0 + 6, 10),
+                        ("Inter", 13).into_font().color(&theme.card_background),
+                    ))?;
+                }
+
+                x += w;
+            }
+        }
+    }
+
+    root.present()?;
+    Ok(())
+}
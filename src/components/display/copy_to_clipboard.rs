@@ -0,0 +1,59 @@
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::window;
+use yew::prelude::*;
+
+/// How long the "Copied!" confirmation stays up before reverting to the normal label
+const COPIED_CONFIRMATION_MS: u32 = 2000;
+
+#[derive(Properties, PartialEq)]
+pub struct CopyToClipboardProps {
+    /// The text written to the clipboard on click
+    pub text: String,
+    #[prop_or_else(|| "📋 Copy".to_string())]
+    pub label: String,
+    #[prop_or_else(|| "✓ Copied!".to_string())]
+    pub copied_label: String,
+    #[prop_or_else(|| "px-3 py-1.5 text-sm font-medium rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors".to_string())]
+    pub class: String,
+}
+
+/// A button that copies `text` to the clipboard via the async Clipboard API, showing a
+/// transient confirmation label before reverting. Generalizes the inline copy-to-clipboard
+/// logic first added to `ComparisonChart`'s "Share" button into a reusable display component.
+#[function_component(CopyToClipboard)]
+pub fn copy_to_clipboard(props: &CopyToClipboardProps) -> Html {
+    let copied = use_state(|| false);
+
+    let onclick = {
+        let text = props.text.clone();
+        let copied = copied.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let text = text.clone();
+            let copied = copied.clone();
+
+            spawn_local(async move {
+                if write_clipboard_text(&text).await.is_ok() {
+                    copied.set(true);
+                    TimeoutFuture::new(COPIED_CONFIRMATION_MS).await;
+                    copied.set(false);
+                }
+            });
+        })
+    };
+
+    html! {
+        <button class={props.class.clone()} onclick={onclick}>
+            { if *copied { props.copied_label.clone() } else { props.label.clone() } }
+        </button>
+    }
+}
+
+/// Write `text` to the clipboard via the async Clipboard API
+async fn write_clipboard_text(text: &str) -> Result<(), JsValue> {
+    let clipboard = window().ok_or("No window available")?.navigator().clipboard();
+    JsFuture::from(clipboard.write_text(text)).await?;
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod copy_to_clipboard;
+pub mod qr_code;
@@ -0,0 +1,21 @@
+use crate::utils::qr_code::render_qr_svg;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct QrCodeProps {
+    pub data: String,
+    #[prop_or(200)]
+    pub size: u32,
+}
+
+/// Render `data` as an inline SVG QR code, e.g. the "Your Bitcoin Rank" results card's
+/// shareable deep-link, so it can be scanned straight off the screen
+#[function_component(QrCode)]
+pub fn qr_code(props: &QrCodeProps) -> Html {
+    match render_qr_svg(&props.data, props.size) {
+        Ok(svg) => Html::from_html_unchecked(AttrValue::from(svg)),
+        Err(e) => html! {
+            <div class="text-sm text-red-600 dark:text-red-400">{format!("Failed to render QR code: {}", e)}</div>
+        },
+    }
+}
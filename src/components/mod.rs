@@ -0,0 +1,4 @@
+pub mod app;
+pub mod charts;
+pub mod display;
+pub mod ui;
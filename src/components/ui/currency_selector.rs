@@ -0,0 +1,39 @@
+use crate::types::currency::Currency;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct CurrencySelectorProps {
+    pub current_currency: Currency,
+    pub on_currency_change: Callback<Currency>,
+}
+
+/// Dropdown for picking which fiat currency BTC amounts are displayed in across the page
+#[function_component(CurrencySelector)]
+pub fn currency_selector(props: &CurrencySelectorProps) -> Html {
+    let onchange = {
+        let on_currency_change = props.on_currency_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(currency) = Currency::from_code(&select.value()) {
+                on_currency_change.emit(currency);
+            }
+        })
+    };
+
+    html! {
+        <select
+            class="bg-white/20 text-white text-sm rounded-lg px-3 py-1 border border-white/30"
+            title="Display currency"
+            aria-label="Display currency"
+            onchange={onchange}
+        >
+            { for Currency::all().iter().map(|currency| html! {
+                <option value={currency.code()} selected={*currency == props.current_currency}>
+                    {currency.code().to_uppercase()}
+                </option>
+            }) }
+        </select>
+    }
+}
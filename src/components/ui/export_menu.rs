@@ -0,0 +1,128 @@
+use crate::types::bitcoin::{BitcoinDistribution, PercentileResult};
+use crate::utils::csv_export;
+use std::collections::HashMap;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{console, window, HtmlAnchorElement, HtmlCanvasElement};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ExportMenuProps {
+    pub result: Option<PercentileResult>,
+    pub wealth_analysis: Option<HashMap<String, f64>>,
+    pub distribution: Option<BitcoinDistribution>,
+}
+
+/// One place to download a computed result: a single CSV covering the percentile result, wealth
+/// concentration analysis, and full distribution, plus a PNG snapshot of every chart canvas
+/// currently on the page. Chart SVG exports stay on each chart's own buttons, since those need
+/// that chart's specific render function rather than a generic canvas snapshot.
+#[function_component(ExportMenu)]
+pub fn export_menu(props: &ExportMenuProps) -> Html {
+    let on_export_csv = {
+        let result = props.result.clone();
+        let wealth_analysis = props.wealth_analysis.clone();
+        let distribution = props.distribution.clone();
+        Callback::from(move |_: MouseEvent| {
+            match csv_export::full_result_to_csv(
+                result.as_ref(),
+                wealth_analysis.as_ref(),
+                distribution.as_ref(),
+            ) {
+                Ok(csv) => {
+                    if let Err(e) = trigger_text_download(&csv, "bitcoin-rank-export.csv", "text/csv") {
+                        console::log_1(&format!("Failed to download export CSV: {:?}", e).into());
+                    }
+                }
+                Err(e) => console::log_1(&format!("Failed to build export CSV: {}", e).into()),
+            }
+        })
+    };
+
+    let on_export_charts_png = Callback::from(move |_: MouseEvent| {
+        if let Err(e) = download_all_chart_canvases() {
+            console::log_1(&format!("Failed to export charts: {:?}", e).into());
+        }
+    });
+
+    html! {
+        <div class="flex items-center gap-2">
+            <button
+                onclick={on_export_csv}
+                class="px-3 py-1 text-sm bg-gray-100 dark:bg-gray-700 hover:bg-orange-100 dark:hover:bg-orange-900 text-gray-700 dark:text-gray-300 rounded-full transition-colors duration-200 hover:text-orange-600 dark:hover:text-orange-400"
+            >
+                {"⬇ Export CSV"}
+            </button>
+            <button
+                onclick={on_export_charts_png}
+                class="px-3 py-1 text-sm bg-gray-100 dark:bg-gray-700 hover:bg-orange-100 dark:hover:bg-orange-900 text-gray-700 dark:text-gray-300 rounded-full transition-colors duration-200 hover:text-orange-600 dark:hover:text-orange-400"
+            >
+                {"⬇ Export Charts (PNG)"}
+            </button>
+        </div>
+    }
+}
+
+/// Snapshot every `<canvas>` currently on the page (the distribution, statistics, and comparison
+/// charts all render into one) as its own numbered PNG download.
+fn download_all_chart_canvases() -> Result<(), JsValue> {
+    let document = window()
+        .ok_or("No window available")?
+        .document()
+        .ok_or("No document available")?;
+
+    let canvases = document
+        .query_selector_all("canvas")
+        .map_err(|_| JsValue::from_str("Failed to query chart canvases"))?;
+
+    for index in 0..canvases.length() {
+        if let Some(canvas) = canvases
+            .get(index)
+            .and_then(|node| node.dyn_into::<HtmlCanvasElement>().ok())
+        {
+            if let Ok(data_url) = canvas.to_data_url_with_type("image/png") {
+                trigger_data_url_download(&data_url, &format!("bitcoin-rank-chart-{}.png", index + 1))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Wrap `content` in a Blob of the given MIME type and trigger a browser download via an object
+/// URL and a synthetic `<a download>` click
+fn trigger_text_download(content: &str, filename: &str, mime_type: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::new();
+    parts.push(&JsValue::from_str(content));
+
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    let result = trigger_anchor_download(&url, filename);
+    web_sys::Url::revoke_object_url(&url)?;
+
+    result
+}
+
+/// Trigger a browser download directly from a `data:` URL (e.g. `canvas.to_data_url`)
+fn trigger_data_url_download(data_url: &str, filename: &str) -> Result<(), JsValue> {
+    trigger_anchor_download(data_url, filename)
+}
+
+fn trigger_anchor_download(href: &str, filename: &str) -> Result<(), JsValue> {
+    let document = window()
+        .ok_or("No window available")?
+        .document()
+        .ok_or("No document available")?;
+
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()
+        .map_err(|_| JsValue::from_str("Failed to create anchor element"))?;
+    anchor.set_href(href);
+    anchor.set_download(filename);
+    anchor.click();
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::components::ui::theme_toggle::ThemeToggle;
+use crate::utils::chart_theme::ChartPalette;
 use crate::utils::theme::Theme;
 use stylist::yew::styled_component;
 use yew::prelude::*;
@@ -7,6 +8,9 @@ use yew::prelude::*;
 pub struct HeaderProps {
     pub current_theme: Theme,
     pub on_theme_change: Callback<Theme>,
+    pub current_palette: ChartPalette,
+    pub on_palette_change: Callback<ChartPalette>,
+    pub on_reset_to_system: Callback<()>,
 }
 
 #[styled_component(Header)]
@@ -51,6 +55,9 @@ pub fn header(props: &HeaderProps) -> Html {
                         <ThemeToggle
                             current_theme={props.current_theme}
                             on_theme_change={props.on_theme_change.clone()}
+                            current_palette={props.current_palette}
+                            on_palette_change={props.on_palette_change.clone()}
+                            on_reset_to_system={props.on_reset_to_system.clone()}
                         />
                     </div>
 
@@ -59,6 +66,9 @@ pub fn header(props: &HeaderProps) -> Html {
                         <ThemeToggle
                             current_theme={props.current_theme}
                             on_theme_change={props.on_theme_change.clone()}
+                            current_palette={props.current_palette}
+                            on_palette_change={props.on_palette_change.clone()}
+                            on_reset_to_system={props.on_reset_to_system.clone()}
                         />
                         <button class="p-2 rounded-lg text-gray-600 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-800 transition-colors">
                             <svg class="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
@@ -11,7 +11,7 @@ pub struct LoadingSpinnerProps {
 
 #[derive(Clone, PartialEq)]
 pub enum SpinnerSize {
-    #[allow(dead_code)]
+    /// Fits inline inside a button (e.g. a refresh button mid-request)
     Small,
     Medium,
     Large,
@@ -36,7 +36,7 @@ pub fn loading_spinner(props: &LoadingSpinnerProps) -> Html {
             <div class="flex flex-col items-center space-y-4">
                 <div class={format!("loading-spinner {}", size_class)}></div>
                 if let Some(message) = &props.message {
-                    <p class="text-gray-600 text-sm font-medium">{message}</p>
+                    <p class="text-gray-600 dark:text-gray-300 text-sm font-medium">{message}</p>
                 }
             </div>
         </div>
@@ -47,7 +47,7 @@ pub fn loading_spinner(props: &LoadingSpinnerProps) -> Html {
 pub fn loading_overlay() -> Html {
     html! {
         <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
-            <div class="bg-white rounded-lg p-8 max-w-sm w-full mx-4">
+            <div class="bg-white dark:bg-gray-800 rounded-lg p-8 max-w-sm w-full mx-4">
                 <LoadingSpinner size={SpinnerSize::Large} message={"Loading Bitcoin data..."} />
             </div>
         </div>
@@ -59,9 +59,28 @@ pub fn loading_card() -> Html {
     html! {
         <div class="chart-container p-6">
             <div class="animate-pulse">
-                <div class="h-4 bg-gray-200 rounded w-3/4 mb-4"></div>
-                <div class="h-64 bg-gray-200 rounded mb-4"></div>
-                <div class="h-4 bg-gray-200 rounded w-1/2"></div>
+                <div class="h-4 bg-gray-200 dark:bg-gray-700 rounded w-3/4 mb-4"></div>
+                <div class="h-64 bg-gray-200 dark:bg-gray-700 rounded mb-4"></div>
+                <div class="h-4 bg-gray-200 dark:bg-gray-700 rounded w-1/2"></div>
+            </div>
+        </div>
+    }
+}
+
+/// Skeleton sized to match the plotters chart containers (`chart-container`'s fixed-height canvas
+/// area), for a chart that's still loading instead of a generic `LoadingCard`.
+#[styled_component(SkeletonChart)]
+pub fn skeleton_chart() -> Html {
+    html! {
+        <div class="chart-container p-6">
+            <div class="animate-pulse space-y-4">
+                <div class="h-5 bg-gray-200 dark:bg-gray-700 rounded w-1/3"></div>
+                <div class="h-80 bg-gray-200 dark:bg-gray-700 rounded"></div>
+                <div class="flex space-x-4">
+                    <div class="h-3 bg-gray-200 dark:bg-gray-700 rounded w-16"></div>
+                    <div class="h-3 bg-gray-200 dark:bg-gray-700 rounded w-16"></div>
+                    <div class="h-3 bg-gray-200 dark:bg-gray-700 rounded w-16"></div>
+                </div>
             </div>
         </div>
     }
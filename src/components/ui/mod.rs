@@ -0,0 +1,7 @@
+pub mod currency_selector;
+pub mod export_menu;
+pub mod footer;
+pub mod header;
+pub mod loading_spinner;
+pub mod refresh_control;
+pub mod theme_toggle;
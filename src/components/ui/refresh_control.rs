@@ -0,0 +1,136 @@
+use crate::components::ui::loading_spinner::{LoadingSpinner, SpinnerSize};
+use stylist::yew::styled_component;
+use web_sys::TouchEvent;
+use yew::prelude::*;
+
+/// Downward drag, in pixels, before the pull indicator starts revealing itself.
+const PULL_REVEAL_THRESHOLD_PX: f64 = 24.0;
+/// Downward drag, in pixels, past which releasing the touch triggers a refresh.
+const PULL_TRIGGER_THRESHOLD_PX: f64 = 80.0;
+
+#[derive(Properties, PartialEq)]
+pub struct RefreshControlProps {
+    /// Fired once per completed pull-past-threshold release, or desktop button click.
+    pub on_refresh: Callback<()>,
+    /// While `true`, shows the spinner in place of the pull indicator/button and ignores new
+    /// trigger attempts, so a refresh already in flight can't be re-triggered.
+    #[prop_or_default]
+    pub refreshing: bool,
+    pub children: Children,
+}
+
+/// Wraps dashboard content with a standard mobile pull-to-refresh gesture plus a desktop refresh
+/// button, so refetching live data doesn't require reloading the whole WASM app. The gesture only
+/// engages while the wrapped container is scrolled to the very top, matching how pull-to-refresh
+/// behaves in native apps.
+#[styled_component(RefreshControl)]
+pub fn refresh_control(props: &RefreshControlProps) -> Html {
+    let container_ref = use_node_ref();
+    let touch_start_y = use_mut_ref(|| None::<f64>);
+    let pull_distance = use_state(|| 0.0_f64);
+
+    let on_touch_start = {
+        let container_ref = container_ref.clone();
+        let touch_start_y = touch_start_y.clone();
+        let refreshing = props.refreshing;
+
+        Callback::from(move |e: TouchEvent| {
+            if refreshing {
+                return;
+            }
+
+            let Some(container) = container_ref.cast::<web_sys::Element>() else {
+                return;
+            };
+            if container.scroll_top() > 0 {
+                return;
+            }
+
+            if let Some(touch) = e.touches().get(0) {
+                *touch_start_y.borrow_mut() = Some(touch.client_y() as f64);
+            }
+        })
+    };
+
+    let on_touch_move = {
+        let touch_start_y = touch_start_y.clone();
+        let pull_distance = pull_distance.clone();
+
+        Callback::from(move |e: TouchEvent| {
+            let Some(start_y) = *touch_start_y.borrow() else {
+                return;
+            };
+            let Some(touch) = e.touches().get(0) else {
+                return;
+            };
+
+            let delta = touch.client_y() as f64 - start_y;
+            if delta > 0.0 {
+                pull_distance.set(delta);
+            }
+        })
+    };
+
+    let on_touch_end = {
+        let touch_start_y = touch_start_y.clone();
+        let pull_distance = pull_distance.clone();
+        let on_refresh = props.on_refresh.clone();
+        let refreshing = props.refreshing;
+
+        Callback::from(move |_: TouchEvent| {
+            *touch_start_y.borrow_mut() = None;
+
+            if !refreshing && *pull_distance >= PULL_TRIGGER_THRESHOLD_PX {
+                on_refresh.emit(());
+            }
+
+            pull_distance.set(0.0);
+        })
+    };
+
+    let on_button_click = {
+        let on_refresh = props.on_refresh.clone();
+        let refreshing = props.refreshing;
+
+        Callback::from(move |_: MouseEvent| {
+            if !refreshing {
+                on_refresh.emit(());
+            }
+        })
+    };
+
+    let indicator_progress = (*pull_distance / PULL_TRIGGER_THRESHOLD_PX).min(1.0);
+    let show_pull_indicator = !props.refreshing && *pull_distance > PULL_REVEAL_THRESHOLD_PX;
+
+    html! {
+        <div
+            ref={container_ref}
+            class="relative"
+            ontouchstart={on_touch_start}
+            ontouchmove={on_touch_move}
+            ontouchend={on_touch_end}
+        >
+            <div class="hidden md:flex justify-end mb-2">
+                <button
+                    class="px-3 py-1 text-sm bg-white/20 hover:bg-white/30 rounded-full transition-colors duration-200 disabled:opacity-50 disabled:cursor-not-allowed"
+                    onclick={on_button_click}
+                    disabled={props.refreshing}
+                >
+                    { if props.refreshing { "↻ Refreshing…" } else { "↻ Refresh" } }
+                </button>
+            </div>
+
+            if props.refreshing {
+                <div class="flex justify-center py-2">
+                    <LoadingSpinner size={SpinnerSize::Small} message={"Refreshing…".to_string()} />
+                </div>
+            } else if show_pull_indicator {
+                <div class="flex justify-center py-2" style={format!("opacity: {}", indicator_progress)}>
+                    <LoadingSpinner size={SpinnerSize::Small} />
+                </div>
+            }
+
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -1,11 +1,18 @@
+use crate::utils::chart_theme::ChartPalette;
 use crate::utils::theme::Theme;
 use stylist::yew::styled_component;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct ThemeToggleProps {
     pub current_theme: Theme,
     pub on_theme_change: Callback<Theme>,
+    pub current_palette: ChartPalette,
+    pub on_palette_change: Callback<ChartPalette>,
+    /// Clears the stored theme preference and reverts to following the OS color scheme
+    pub on_reset_to_system: Callback<()>,
 }
 
 #[styled_component(ThemeToggle)]
@@ -20,27 +27,74 @@ pub fn theme_toggle(props: &ThemeToggleProps) -> Html {
         })
     };
 
+    let on_palette_select = {
+        let on_palette_change = props.on_palette_change.clone();
+
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(palette) = ChartPalette::from_str(&select.value()) {
+                on_palette_change.emit(palette);
+            }
+        })
+    };
+
     let (icon, tooltip) = match props.current_theme {
         Theme::Light => ("🌙", "Switch to dark mode"),
         Theme::Dark => ("☀️", "Switch to light mode"),
     };
 
+    let on_reset_click = {
+        let on_reset_to_system = props.on_reset_to_system.clone();
+        Callback::from(move |_: MouseEvent| on_reset_to_system.emit(()))
+    };
+
     html! {
-        <button
-            class="relative inline-flex items-center justify-center w-10 h-10 rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 transition-all duration-200 group"
-            onclick={onclick}
-            title={tooltip}
-            aria-label={tooltip}
-        >
-            <span class="text-lg transition-transform duration-200 group-hover:scale-110">
-                {icon}
-            </span>
-
-            // Tooltip
-            <div class="absolute bottom-full left-1/2 transform -translate-x-1/2 mb-2 px-2 py-1 text-xs text-white bg-gray-900 dark:bg-gray-700 rounded opacity-0 group-hover:opacity-100 transition-opacity duration-200 pointer-events-none whitespace-nowrap">
-                {tooltip}
-                <div class="absolute top-full left-1/2 transform -translate-x-1/2 w-0 h-0 border-l-4 border-r-4 border-t-4 border-transparent border-t-gray-900 dark:border-t-gray-700"></div>
-            </div>
-        </button>
+        <div class="flex items-center space-x-2">
+            <button
+                class="relative inline-flex items-center justify-center w-10 h-10 rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 transition-all duration-200 group"
+                onclick={onclick}
+                title={tooltip}
+                aria-label={tooltip}
+            >
+                <span class="text-lg transition-transform duration-200 group-hover:scale-110">
+                    {icon}
+                </span>
+
+                // Tooltip
+                <div class="absolute bottom-full left-1/2 transform -translate-x-1/2 mb-2 px-2 py-1 text-xs text-white bg-gray-900 dark:bg-gray-700 rounded opacity-0 group-hover:opacity-100 transition-opacity duration-200 pointer-events-none whitespace-nowrap">
+                    {tooltip}
+                    <div class="absolute top-full left-1/2 transform -translate-x-1/2 w-0 h-0 border-l-4 border-r-4 border-t-4 border-transparent border-t-gray-900 dark:border-t-gray-700"></div>
+                </div>
+            </button>
+
+            <button
+                class="w-10 h-10 flex items-center justify-center rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-500 dark:text-gray-400 transition-all duration-200"
+                onclick={on_reset_click}
+                title="Follow system color scheme"
+                aria-label="Follow system color scheme"
+            >
+                <span class="text-sm">{"↺"}</span>
+            </button>
+
+            <select
+                class="h-10 px-2 text-sm rounded-lg border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-800 text-gray-700 dark:text-gray-300"
+                title="Chart color palette"
+                aria-label="Chart color palette"
+                onchange={on_palette_select}
+            >
+                { for ChartPalette::all().iter().map(|palette| {
+                    let label = match palette {
+                        ChartPalette::Mempool => "Mempool",
+                        ChartPalette::Classic => "Classic",
+                        ChartPalette::Roundy => "Roundy",
+                    };
+                    html! {
+                        <option value={palette.as_str()} selected={*palette == props.current_palette}>
+                            {label}
+                        </option>
+                    }
+                }) }
+            </select>
+        </div>
     }
 }
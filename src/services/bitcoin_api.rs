@@ -1,26 +1,67 @@
+use crate::types::api::{ApiConfig, ApiResponse, BitInfoChartsResponse};
 use crate::types::bitcoin::{BitcoinDistribution, WealthRange};
-use reqwest::Client;
+use crate::types::currency::Currency;
+use crate::utils::validators::validate_bitcoin_address;
+use gloo_timers::future::TimeoutFuture;
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 use web_sys::console;
 
-// BitInfoCharts API response format
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BitInfoChartsData {
-    pub addresses: Vec<AddressData>,
-    pub total_addresses: u64,
-    pub total_supply: f64,
-    pub last_updated: u64,
+/// The subset of blockchain.info's `/stats` response this app actually reads. Both fields are
+/// required (no `#[serde(default)]`) so a missing one is a hard parse error with a field path
+/// rather than a silently-substituted placeholder.
+#[derive(Debug, Deserialize)]
+struct BlockchainInfoStats {
+    total_bitcoins: f64,
+    n_unique_addresses: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AddressData {
-    pub range: String,
-    pub addresses: u64,
-    pub btc: f64,
-    pub percentage: f64,
+/// CoinGecko's `/simple/price?ids=bitcoin&vs_currencies=usd` response, typed instead of indexed
+/// through a `serde_json::Value` so a missing/renamed field fails loudly with its path (e.g.
+/// `bitcoin.usd`) instead of silently falling back to a guessed price.
+#[derive(Debug, Deserialize)]
+struct CoinGeckoPriceResponse {
+    bitcoin: CoinGeckoBitcoinPrice,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinGeckoBitcoinPrice {
+    usd: f64,
+}
+
+/// CoinGecko's `/simple/price?ids=bitcoin&vs_currencies=<codes>` response when multiple
+/// currencies are requested at once, keyed by lowercase currency code (e.g. `"usd"`, `"eur"`)
+#[derive(Debug, Deserialize)]
+struct CoinGeckoMultiPriceResponse {
+    bitcoin: HashMap<String, f64>,
 }
 
+/// Deserialize `body` as `T`, reporting the exact JSON field path on failure (via
+/// `serde_path_to_error`) instead of a bare "invalid type" message with no indication of which
+/// field broke.
+fn parse_with_path<T: DeserializeOwned>(body: &str, source_label: &str) -> Result<T, String> {
+    let mut deserializer = serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|e| format!("{} response failed to parse at '{}': {}", source_label, e.path(), e.inner()))
+}
+
+/// Bitcoin's standard dust limit: outputs at or below this many satoshis cost more to spend than
+/// they're worth, so an address sitting at or below it is unambiguously `WealthCategory::Dust`
+/// regardless of how close its balance is to that tier's upper bound.
+const DUST_THRESHOLD_SATS: i64 = 546;
+
+/// Starting delay for `fetch_bitinfocharts_with_config`'s exponential backoff, and the width of
+/// the random jitter added on top of each wait.
+const RETRY_BASE_DELAY_MS: u32 = 500;
+/// Upper bound on the backoff delay between attempts, regardless of how many attempts have
+/// already elapsed.
+const RETRY_DELAY_CEILING_MS: u32 = 8_000;
+
 // Alternative API: Bitcoin Rich List
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RichListEntry {
@@ -30,6 +71,20 @@ pub struct RichListEntry {
     pub percentage: f64,
 }
 
+/// The same wealth-tier boundaries `WealthCategory`/the mock generators use, so a real
+/// per-address distribution buckets into ranges the rest of the app already understands.
+const WEALTH_BIN_BOUNDS: [(f64, f64); 9] = [
+    (0.0, 0.001),
+    (0.001, 0.01),
+    (0.01, 0.1),
+    (0.1, 1.0),
+    (1.0, 10.0),
+    (10.0, 100.0),
+    (100.0, 1000.0),
+    (1000.0, 10_000.0),
+    (10_000.0, f64::INFINITY),
+];
+
 pub struct BitcoinApiService {
     client: Client,
     base_urls: HashMap<String, String>,
@@ -40,8 +95,8 @@ impl BitcoinApiService {
         let client = Client::new();
         let mut base_urls = HashMap::new();
         
-        // Primary APIs
-        base_urls.insert("bitinfocharts".to_string(), "https://bitinfocharts.com/api".to_string());
+        // Primary APIs (bitinfocharts' base URL lives in `ApiConfig::default`, since
+        // `fetch_bitinfocharts_with_config` takes its own config rather than reading this map)
         base_urls.insert("blockchain_info".to_string(), "https://api.blockchain.info".to_string());
         base_urls.insert("coingecko".to_string(), "https://api.coingecko.com/api/v3".to_string());
         
@@ -51,23 +106,164 @@ impl BitcoinApiService {
         }
     }
 
-    /// Fetch Bitcoin distribution data from multiple sources
+    /// Fetch Bitcoin distribution data from multiple sources, tried in priority order. Each
+    /// branch logs which fields its provider actually supplied (versus estimated or fabricated),
+    /// and the provider that succeeds records itself in the returned `data_source` so a reader
+    /// can tell a real distribution from an estimated or mock one at a glance.
     pub async fn fetch_bitcoin_distribution(&self) -> Result<BitcoinDistribution, String> {
         console::log_1(&"🔍 Fetching Bitcoin distribution data...".into());
-        
-        // Try different data sources in order of preference
+
+        // 1. bitinfocharts: real per-address balances for every bucket.
+        match self.fetch_from_bitinfocharts().await {
+            Ok(distribution) => {
+                console::log_1(&"✅ bitinfocharts supplied real per-address balances for every bucket".into());
+                return Ok(distribution);
+            }
+            Err(e) => console::log_1(&format!("❌ bitinfocharts failed: {}", e).into()),
+        }
+
+        // 2. blockchain.info: only two aggregate numbers are real; every range below that is
+        // estimated from hard-coded percentages, not measured.
         match self.fetch_from_blockchain_info().await {
             Ok(distribution) => {
-                console::log_1(&"✅ Successfully fetched from blockchain.info".into());
-                Ok(distribution)
+                console::log_1(
+                    &"✅ blockchain.info supplied total_bitcoins/n_unique_addresses; per-range figures are estimated"
+                        .into(),
+                );
+                return Ok(distribution);
             }
-            Err(e) => {
-                console::log_1(&format!("❌ blockchain.info failed: {}", e).into());
-                
-                // Fallback to mock data for development
-                console::log_1(&"🔄 Using mock data for development".into());
-                Ok(self.generate_mock_distribution())
+            Err(e) => console::log_1(&format!("❌ blockchain.info failed: {}", e).into()),
+        }
+
+        // 3. Last resort: every field below is fabricated mock data.
+        console::log_1(&"🔄 All live sources failed; falling back to fully fabricated mock data".into());
+        Ok(self.generate_mock_distribution())
+    }
+
+    /// Fetch a real per-address wealth distribution from bitinfocharts' rich list, which reports
+    /// actual address counts and BTC totals per balance bucket (unlike `fetch_from_blockchain_info`,
+    /// which only has two aggregate numbers to fabricate a shape from). Goes through
+    /// `fetch_bitinfocharts_with_config`'s retry/backoff/timeout policy rather than a single
+    /// best-effort request.
+    async fn fetch_from_bitinfocharts(&self) -> Result<BitcoinDistribution, String> {
+        let response = self.fetch_bitinfocharts_with_config(&ApiConfig::default()).await;
+
+        let data = response
+            .data
+            .ok_or_else(|| response.error.unwrap_or_else(|| "bitinfocharts request failed".to_string()))?;
+
+        if data.addresses.is_empty() {
+            return Err("bitinfocharts returned no address buckets".to_string());
+        }
+
+        Ok(self.distribution_from_bitinfocharts(data))
+    }
+
+    /// Fetch the bitinfocharts rich list through `config`'s retry policy, turning its previously
+    /// unused `base_url`/`timeout_ms`/`retry_count` fields into real behavior: each attempt races
+    /// the request against a `timeout_ms` timer (a timeout counts as a retryable failure, same as
+    /// a network error or a 5xx/429 response), and attempts are spaced by an exponential backoff
+    /// with jitter so retries don't pile onto the exact same tick. Gives up and returns
+    /// `ApiResponse::error` with the last failure's message once `retry_count` attempts are spent.
+    pub async fn fetch_bitinfocharts_with_config(&self, config: &ApiConfig) -> ApiResponse<BitInfoChartsResponse> {
+        let mut last_error = "retry_count was 0; no attempt was made".to_string();
+
+        for attempt in 0..config.retry_count {
+            match with_timeout(self.fetch_bitinfocharts_response(&config.base_url), config.timeout_ms).await {
+                Some(Ok(response)) => return ApiResponse::success(response),
+                Some(Err((message, retryable))) => {
+                    last_error = message;
+                    if !retryable {
+                        return ApiResponse::error(last_error);
+                    }
+                }
+                None => {
+                    last_error = format!("Request timed out after {}ms", config.timeout_ms);
+                }
             }
+
+            if attempt + 1 < config.retry_count {
+                TimeoutFuture::new(backoff_delay_ms(attempt)).await;
+            }
+        }
+
+        ApiResponse::error(last_error)
+    }
+
+    /// One unretried attempt at the bitinfocharts rich list. The `bool` in the error case is
+    /// whether the failure is worth retrying (network errors and 5xx/429 are; a parse error on an
+    /// otherwise-successful response is not, since retrying won't change the response body).
+    async fn fetch_bitinfocharts_response(&self, base_url: &str) -> Result<BitInfoChartsResponse, (String, bool)> {
+        let url = format!("{}/richlist?coin=btc", base_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0 (compatible; BitcoinWealthComparison/1.0)")
+            .send()
+            .await
+            .map_err(|e| (format!("Network error: {}", e), true))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err((format!("API error: {}", status), is_retryable_status(status)));
+        }
+
+        response
+            .json::<BitInfoChartsResponse>()
+            .await
+            .map_err(|e| (format!("JSON parsing error: {}", e), false))
+    }
+
+    /// Bucket real per-address balance data into the app's fixed `WEALTH_BIN_BOUNDS`, summing
+    /// each bucket's real `address_count` and `total_btc` rather than assuming a shape.
+    fn distribution_from_bitinfocharts(&self, data: BitInfoChartsResponse) -> BitcoinDistribution {
+        let mut bucket_addresses = [0u64; WEALTH_BIN_BOUNDS.len()];
+        let mut bucket_btc = [0f64; WEALTH_BIN_BOUNDS.len()];
+
+        for entry in &data.addresses {
+            let Some((min_btc, max_btc)) = parse_range_bounds(&entry.range) else {
+                continue;
+            };
+            let bucket = wealth_bucket_index(min_btc, max_btc);
+            bucket_addresses[bucket] += entry.addresses;
+            bucket_btc[bucket] += entry.btc;
+        }
+
+        let total_addresses = data.total_addresses.max(bucket_addresses.iter().sum());
+        let total_supply = if data.total_supply > 0.0 {
+            data.total_supply
+        } else {
+            bucket_btc.iter().sum()
+        };
+
+        let ranges = WEALTH_BIN_BOUNDS
+            .iter()
+            .enumerate()
+            .map(|(i, (min_btc, max_btc))| WealthRange {
+                min_btc: *min_btc,
+                max_btc: *max_btc,
+                address_count: bucket_addresses[i],
+                total_btc: bucket_btc[i],
+                percentage_of_addresses: if total_addresses > 0 {
+                    bucket_addresses[i] as f64 / total_addresses as f64 * 100.0
+                } else {
+                    0.0
+                },
+                percentage_of_supply: if total_supply > 0.0 {
+                    bucket_btc[i] / total_supply * 100.0
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+
+        BitcoinDistribution {
+            ranges,
+            total_addresses,
+            total_supply,
+            timestamp: js_sys::Date::now() as u64,
+            data_source: "bitinfocharts rich list (real per-address distribution)".to_string(),
         }
     }
 
@@ -86,16 +282,10 @@ impl BitcoinApiService {
             return Err(format!("API error: {}", response.status()));
         }
 
-        let stats: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("JSON parsing error: {}", e))?;
+        let body = response.text().await.map_err(|e| format!("Failed to read response body: {}", e))?;
+        let stats: BlockchainInfoStats = parse_with_path(&body, "blockchain.info /stats")?;
 
-        // Extract basic stats and create distribution
-        let total_bitcoins = stats["total_bitcoins"].as_f64().unwrap_or(21_000_000.0);
-        let estimated_addresses = stats["n_unique_addresses"].as_u64().unwrap_or(1_000_000);
-        
-        Ok(self.create_distribution_from_stats(total_bitcoins, estimated_addresses))
+        Ok(self.create_distribution_from_stats(stats.total_bitcoins, stats.n_unique_addresses))
     }
 
     /// Create Bitcoin distribution from basic stats
@@ -286,12 +476,42 @@ impl BitcoinApiService {
             return Ok(50000.0); // Fallback price
         }
 
-        let price_data: serde_json::Value = response
-            .json()
+        let body = response.text().await.map_err(|e| format!("Failed to read response body: {}", e))?;
+        let price_data: CoinGeckoPriceResponse = parse_with_path(&body, "CoinGecko /simple/price")?;
+
+        Ok(price_data.bitcoin.usd)
+    }
+
+    /// Get the BTC price in every supported fiat currency at once, so the UI can let users flip
+    /// between them without a round trip per currency. Keyed by `Currency::code()`.
+    pub async fn fetch_exchange_rates(&self) -> Result<HashMap<String, f64>, String> {
+        let codes = Currency::all()
+            .iter()
+            .map(|c| c.code())
+            .collect::<Vec<_>>()
+            .join(",");
+        let url = format!(
+            "{}/simple/price?ids=bitcoin&vs_currencies={}",
+            self.base_urls["coingecko"], codes
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0 (compatible; BitcoinWealthComparison/1.0)")
+            .send()
             .await
-            .map_err(|_| "Failed to parse price data".to_string())?;
+            .map_err(|e| format!("Network error: {}", e))?;
 
-        Ok(price_data["bitcoin"]["usd"].as_f64().unwrap_or(50000.0))
+        if !response.status().is_success() {
+            return Ok(HashMap::from([("usd".to_string(), 50000.0)]));
+        }
+
+        let body = response.text().await.map_err(|e| format!("Failed to read response body: {}", e))?;
+        let price_data: CoinGeckoMultiPriceResponse =
+            parse_with_path(&body, "CoinGecko /simple/price (multi-currency)")?;
+
+        Ok(price_data.bitcoin)
     }
 
     /// Get Bitcoin network statistics
@@ -308,6 +528,69 @@ impl BitcoinApiService {
         
         Ok(stats)
     }
+
+    /// Look up a real address's confirmed balance, so a user can paste an address instead of
+    /// guessing an amount. Validates the address format up front, then queries blockchain.info's
+    /// `addressbalance` endpoint the same way Electrum-style wallets derive a balance from an
+    /// address's confirmed scriptPubKey outputs.
+    ///
+    /// Addresses that only have unconfirmed (mempool) activity, or that the explorer doesn't
+    /// recognize at all, return a clear error instead of silently reporting a zero balance.
+    /// Balances at or below [`DUST_THRESHOLD_SATS`] are still returned normally; they flow into
+    /// `calculate_percentile` and land in `WealthCategory::Dust` like any other sub-0.001 BTC
+    /// holding.
+    pub async fn fetch_address_balance(&self, address: &str) -> Result<f64, String> {
+        validate_bitcoin_address(address)?;
+
+        let confirmed_sats = self.fetch_address_balance_sats(address, 1).await?;
+        let including_unconfirmed_sats = self.fetch_address_balance_sats(address, 0).await?;
+
+        if confirmed_sats == 0 && including_unconfirmed_sats > 0 {
+            return Err(format!(
+                "{} only has unconfirmed transactions; its percentile will update once they confirm",
+                address
+            ));
+        }
+
+        if confirmed_sats <= DUST_THRESHOLD_SATS {
+            console::log_1(
+                &format!(
+                    "ℹ️ {} holds {} sats, at or below the {}-sat dust threshold; classifying as Dust",
+                    address, confirmed_sats, DUST_THRESHOLD_SATS
+                )
+                .into(),
+            );
+        }
+
+        Ok(confirmed_sats as f64 / 100_000_000.0)
+    }
+
+    /// Fetch a single address's balance in satoshis, counting only transactions with at least
+    /// `confirmations` confirmations (`0` includes unconfirmed mempool activity).
+    async fn fetch_address_balance_sats(&self, address: &str, confirmations: u32) -> Result<i64, String> {
+        let url = format!(
+            "{}/q/addressbalance/{}?confirmations={}",
+            self.base_urls["blockchain_info"], address, confirmations
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0 (compatible; BitcoinWealthComparison/1.0)")
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Unknown address: {} (explorer returned {})", address, response.status()));
+        }
+
+        let body = response.text().await.map_err(|e| format!("Failed to read balance response: {}", e))?;
+
+        body.trim()
+            .parse::<i64>()
+            .map_err(|_| format!("Unexpected balance response for {}: {}", address, body))
+    }
 }
 
 impl Default for BitcoinApiService {
@@ -315,3 +598,83 @@ impl Default for BitcoinApiService {
         Self::new()
     }
 }
+
+/// Parse a bitinfocharts-style bucket label such as `"0.1-1"` or `"10000-"` (open-ended, meaning
+/// "and above") into `(min_btc, max_btc)`.
+fn parse_range_bounds(range: &str) -> Option<(f64, f64)> {
+    let (min_str, max_str) = range.split_once('-')?;
+    let min_btc = min_str.trim().parse::<f64>().ok()?;
+    let max_btc = if max_str.trim().is_empty() {
+        f64::INFINITY
+    } else {
+        max_str.trim().parse::<f64>().ok()?
+    };
+    Some((min_btc, max_btc))
+}
+
+/// Find the `WEALTH_BIN_BOUNDS` index a balance range belongs in, keyed off its representative
+/// balance (the midpoint, or the floor for an open-ended top range) the same way
+/// `types::bitcoin` ranks ranges for the Gini/Lorenz calculation.
+fn wealth_bucket_index(min_btc: f64, max_btc: f64) -> usize {
+    let representative = if max_btc.is_infinite() { min_btc } else { (min_btc + max_btc) / 2.0 };
+    WEALTH_BIN_BOUNDS
+        .iter()
+        .position(|(lo, hi)| representative >= *lo && representative < *hi)
+        .unwrap_or(WEALTH_BIN_BOUNDS.len() - 1)
+}
+
+/// HTTP statuses worth retrying: rate-limited (429) or a server-side failure (5xx). A 4xx other
+/// than 429 means the request itself is wrong and retrying it would just fail the same way.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status.as_u16() == 429 || status.is_server_error()
+}
+
+/// `RETRY_BASE_DELAY_MS * 2^attempt`, capped at `RETRY_DELAY_CEILING_MS`, plus a random jitter in
+/// `[0, RETRY_BASE_DELAY_MS)` so that many clients backing off at once don't all retry on the
+/// same tick.
+fn backoff_delay_ms(attempt: u32) -> u32 {
+    let exponential = RETRY_BASE_DELAY_MS.saturating_mul(1u32 << attempt.min(16));
+    let capped = exponential.min(RETRY_DELAY_CEILING_MS);
+    let jitter = (js_sys::Math::random() * RETRY_BASE_DELAY_MS as f64) as u32;
+    capped + jitter
+}
+
+/// Race `fut` against a `timeout_ms` timer, resolving to `None` if the timer wins first. This is
+/// how `fetch_bitinfocharts_with_config` enforces `ApiConfig::timeout_ms` per attempt without
+/// pulling in a whole async-runtime crate for a single two-way race.
+async fn with_timeout<T>(fut: impl Future<Output = T>, timeout_ms: u32) -> Option<T> {
+    struct Race<A, B> {
+        a: Pin<Box<A>>,
+        b: Pin<Box<B>>,
+    }
+
+    enum Outcome<TA, TB> {
+        First(TA),
+        Second(TB),
+    }
+
+    impl<A: Future, B: Future> Future for Race<A, B> {
+        type Output = Outcome<A::Output, B::Output>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            if let Poll::Ready(value) = this.a.as_mut().poll(cx) {
+                return Poll::Ready(Outcome::First(value));
+            }
+            if let Poll::Ready(value) = this.b.as_mut().poll(cx) {
+                return Poll::Ready(Outcome::Second(value));
+            }
+            Poll::Pending
+        }
+    }
+
+    let race = Race {
+        a: Box::pin(fut),
+        b: Box::pin(TimeoutFuture::new(timeout_ms)),
+    };
+
+    match race.await {
+        Outcome::First(value) => Some(value),
+        Outcome::Second(_) => None,
+    }
+}
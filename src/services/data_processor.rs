@@ -1,20 +1,45 @@
-use crate::types::bitcoin::{BitcoinDistribution, WealthRange};
+use crate::types::bitcoin::{
+    BitcoinDistribution, FiatDistribution, FiatWealthRange, PriceProvider, WealthRange,
+};
 use crate::utils::validators::validate_bitcoin_amount;
 use std::collections::HashMap;
 use web_sys::console;
 
+/// Maximum number of historical snapshots kept before the oldest is evicted
+const DEFAULT_SNAPSHOT_CAPACITY: usize = 20;
+
+/// Per-range change between two snapshots of a `BitcoinDistribution`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WealthRangeFlow {
+    pub min_btc: f64,
+    pub max_btc: f64,
+    pub address_count_delta: i64,
+    pub total_btc_delta: f64,
+}
+
+/// Diff between two dated/height-keyed distribution snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistributionFlow {
+    pub from_height: u64,
+    pub to_height: u64,
+    pub ranges: Vec<WealthRangeFlow>,
+    /// Net BTC that migrated past each band boundary, carried low-to-high
+    pub net_migration_btc: Vec<f64>,
+}
+
 pub struct DataProcessor {
-    #[allow(dead_code)]
-    cache: HashMap<String, BitcoinDistribution>,
+    cache: HashMap<u64, BitcoinDistribution>,
+    snapshot_capacity: usize,
 }
 
 impl DataProcessor {
     pub fn new() -> Self {
         Self {
             cache: HashMap::new(),
+            snapshot_capacity: DEFAULT_SNAPSHOT_CAPACITY,
         }
     }
-    
+
     /// Process raw Bitcoin distribution data
     #[allow(dead_code)]
     pub fn process_raw_data(&self, _raw_data: &str) -> Result<BitcoinDistribution, String> {
@@ -196,6 +221,69 @@ impl DataProcessor {
         cumulative
     }
     
+    /// Query a window of the distribution, either as raw per-range increments or as a
+    /// running cumulative curve seeded by the totals accumulated before `from_btc`.
+    ///
+    /// Returns `(base_address_pct, base_supply_pct, Vec<(bitcoin_amount, address_pct, supply_pct)>)`
+    /// where `base_*` is the cumulative address-% and supply-% accumulated before `from_btc`,
+    /// and the vector covers only the ranges intersecting `[from_btc, to_btc)`. `to_btc` is
+    /// clamped to the highest finite range bound, and an open-ended top range is treated as
+    /// intersecting any window that reaches it. If no ranges intersect the window, the
+    /// returned vector is empty but `base_*` is still valid.
+    pub fn query_distribution(
+        &self,
+        distribution: &BitcoinDistribution,
+        from_btc: f64,
+        to_btc: f64,
+        cumulative: bool,
+    ) -> (f64, f64, Vec<(f64, f64, f64)>) {
+        let mut sorted_ranges = distribution.ranges.clone();
+        self.sort_ranges(&mut sorted_ranges);
+
+        let max_bound = sorted_ranges
+            .iter()
+            .map(|r| if r.max_btc.is_finite() { r.max_btc } else { r.min_btc })
+            .fold(0.0, f64::max);
+        let to_btc = if to_btc.is_finite() {
+            to_btc.min(max_bound)
+        } else {
+            to_btc
+        };
+
+        let (base_addresses, base_supply) = sorted_ranges
+            .iter()
+            .filter(|r| r.max_btc <= from_btc)
+            .fold((0.0, 0.0), |(a, s), r| {
+                (a + r.percentage_of_addresses, s + r.percentage_of_supply)
+            });
+
+        let mut running_addresses = base_addresses;
+        let mut running_supply = base_supply;
+        let mut window: Vec<(f64, f64, f64)> = Vec::new();
+
+        for range in sorted_ranges
+            .iter()
+            .filter(|r| {
+                r.max_btc > from_btc
+                    && (r.min_btc < to_btc || (r.max_btc.is_infinite() && r.min_btc <= to_btc))
+            })
+        {
+            if cumulative {
+                running_addresses += range.percentage_of_addresses;
+                running_supply += range.percentage_of_supply;
+                window.push((range.max_btc, running_addresses, running_supply));
+            } else {
+                window.push((
+                    range.max_btc,
+                    range.percentage_of_addresses,
+                    range.percentage_of_supply,
+                ));
+            }
+        }
+
+        (base_addresses, base_supply, window)
+    }
+
     /// Find the range that contains a specific Bitcoin amount
     pub fn find_range_for_amount<'a>(&self, amount: f64, distribution: &'a BitcoinDistribution) -> Option<&'a WealthRange> {
         // Validate input
@@ -247,38 +335,170 @@ impl DataProcessor {
         stats.insert("top_5_percent_wealth".to_string(), top_5_percent);
         stats.insert("top_10_percent_wealth".to_string(), top_10_percent);
         
-        // Calculate median (50th percentile)
-        let median = self.calculate_percentile_amount(distribution, 50.0);
+        // Calculate the standard percentile set in a single pass
+        let percentile_points = self.calculate_percentiles(distribution, &[10.0, 25.0, 50.0, 75.0, 90.0]);
+        for (percentile, amount) in &percentile_points {
+            stats.insert(format!("p{}_amount", *percentile as i64), *amount);
+        }
+
+        let median = percentile_points
+            .iter()
+            .find(|(percentile, _)| *percentile == 50.0)
+            .map(|(_, amount)| *amount)
+            .unwrap_or(0.0);
         stats.insert("median_amount".to_string(), median);
-        
+
         stats
     }
-    
+
+    /// Calculate the Bitcoin amount at each requested percentile in a single pass over
+    /// the cumulative address curve, sorting the ranges only once. A percentile landing
+    /// in the open-ended top range (`max_btc == INFINITY`) resolves to that band's
+    /// `min_btc` rather than infinity.
+    pub fn calculate_percentiles(
+        &self,
+        distribution: &BitcoinDistribution,
+        percentiles: &[f64],
+    ) -> Vec<(f64, f64)> {
+        let mut sorted_ranges = distribution.ranges.clone();
+        self.sort_ranges(&mut sorted_ranges);
+
+        let mut targets: Vec<f64> = percentiles.to_vec();
+        targets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut amounts: HashMap<u64, f64> = HashMap::new();
+        let mut cumulative_addresses = 0.0;
+        let mut target_idx = 0;
+
+        for range in &sorted_ranges {
+            let new_cumulative = cumulative_addresses + range.percentage_of_addresses;
+
+            while target_idx < targets.len() && targets[target_idx] <= new_cumulative {
+                let percentile = targets[target_idx];
+                let position = if range.percentage_of_addresses > 0.0 {
+                    (percentile - cumulative_addresses) / range.percentage_of_addresses
+                } else {
+                    0.0
+                };
+
+                let amount = if range.max_btc == f64::INFINITY {
+                    range.min_btc
+                } else {
+                    range.min_btc + position * (range.max_btc - range.min_btc)
+                };
+
+                amounts.insert(percentile.to_bits(), amount);
+                target_idx += 1;
+            }
+
+            cumulative_addresses = new_cumulative;
+        }
+
+        let max_amount = sorted_ranges
+            .iter()
+            .map(|r| if r.max_btc == f64::INFINITY { r.min_btc } else { r.max_btc })
+            .fold(0.0, f64::max);
+        for percentile in &targets[target_idx..] {
+            amounts.insert(percentile.to_bits(), max_amount);
+        }
+
+        percentiles
+            .iter()
+            .map(|p| (*p, *amounts.get(&p.to_bits()).unwrap_or(&0.0)))
+            .collect()
+    }
+
+    /// Value a distribution in fiat terms at a spot price, applying an adjustable spread
+    ///
+    /// `spread_bps` is a signed spread in basis points (0 = no adjustment) applied as
+    /// `effective_price = price * (1.0 + spread_bps / 10_000.0)` before converting, so
+    /// callers can model bid/ask skew or exchange markup.
+    pub fn value_distribution_in_fiat(
+        &self,
+        distribution: &BitcoinDistribution,
+        price: &PriceProvider,
+        spread_bps: i32,
+    ) -> FiatDistribution {
+        let effective_price = price.spot_price * (1.0 + spread_bps as f64 / 10_000.0);
+
+        let ranges = distribution
+            .ranges
+            .iter()
+            .map(|range| FiatWealthRange {
+                min_btc: range.min_btc,
+                max_btc: range.max_btc,
+                total_fiat: range.total_btc * effective_price,
+            })
+            .collect();
+
+        let stats = self.calculate_statistics(distribution);
+        let mean_amount_fiat = stats.get("mean_amount").unwrap_or(&0.0) * effective_price;
+        let median_amount_fiat = stats.get("median_amount").unwrap_or(&0.0) * effective_price;
+
+        FiatDistribution {
+            currency: price.currency.clone(),
+            ranges,
+            total_supply_fiat: distribution.total_supply * effective_price,
+            mean_amount_fiat,
+            median_amount_fiat,
+            effective_price,
+        }
+    }
+
     /// Calculate Gini coefficient for wealth inequality
-    fn calculate_gini_coefficient(&self, distribution: &BitcoinDistribution) -> f64 {
+    pub fn calculate_gini_coefficient(&self, distribution: &BitcoinDistribution) -> f64 {
         let mut sorted_ranges = distribution.ranges.clone();
         self.sort_ranges(&mut sorted_ranges);
-        
+
         let mut total_area = 0.0;
         let mut _cumulative_addresses = 0.0;
         let mut cumulative_wealth = 0.0;
-        
+
         for range in sorted_ranges {
             let address_proportion = range.percentage_of_addresses / 100.0;
             let wealth_proportion = range.percentage_of_supply / 100.0;
-            
+
             // Calculate area under Lorenz curve
             total_area += address_proportion * (cumulative_wealth + wealth_proportion / 2.0);
-            
+
             _cumulative_addresses += address_proportion;
             cumulative_wealth += wealth_proportion;
         }
-        
+
         // Gini coefficient = 1 - 2 * (area under Lorenz curve)
         let gini = 1.0 - 2.0 * total_area;
         gini.max(0.0).min(1.0) // Clamp to [0, 1]
     }
-    
+
+    /// Build the Lorenz curve for a distribution: cumulative fraction of addresses (X) against
+    /// cumulative fraction of total BTC held (Y), starting at `(0.0, 0.0)`. Ranges with no
+    /// addresses are skipped, and an empty or zero-total distribution yields just the origin.
+    pub fn lorenz_curve_points(&self, distribution: &BitcoinDistribution) -> Vec<(f64, f64)> {
+        let mut sorted_ranges = distribution.ranges.clone();
+        self.sort_ranges(&mut sorted_ranges);
+
+        let mut points = vec![(0.0, 0.0)];
+
+        if distribution.total_addresses == 0 || distribution.total_supply <= 0.0 {
+            return points;
+        }
+
+        let mut cumulative_addresses = 0.0;
+        let mut cumulative_btc = 0.0;
+
+        for range in sorted_ranges {
+            if range.address_count == 0 {
+                continue;
+            }
+
+            cumulative_addresses += range.address_count as f64 / distribution.total_addresses as f64;
+            cumulative_btc += range.total_btc / distribution.total_supply;
+            points.push((cumulative_addresses, cumulative_btc));
+        }
+
+        points
+    }
+
     /// Calculate concentration ratio (what percentage of wealth is held by top X% of addresses)
     fn calculate_concentration_ratio(&self, distribution: &BitcoinDistribution, top_percent: f64) -> f64 {
         let mut sorted_ranges = distribution.ranges.clone();
@@ -306,49 +526,95 @@ impl DataProcessor {
         cumulative_wealth
     }
     
-    /// Calculate the Bitcoin amount at a specific percentile
-    fn calculate_percentile_amount(&self, distribution: &BitcoinDistribution, percentile: f64) -> f64 {
-        let mut sorted_ranges = distribution.ranges.clone();
-        self.sort_ranges(&mut sorted_ranges);
-        
-        let mut cumulative_addresses = 0.0;
-        
-        for range in sorted_ranges {
-            let new_cumulative = cumulative_addresses + range.percentage_of_addresses;
-            
-            if new_cumulative >= percentile {
-                // Interpolate within the range
-                let remaining = percentile - cumulative_addresses;
-                let position = remaining / range.percentage_of_addresses;
-                return range.min_btc + position * (range.max_btc - range.min_btc);
+    /// Store a distribution snapshot keyed by block height, evicting the oldest
+    /// snapshot if the capacity bound is exceeded
+    pub fn snapshot_distribution(&mut self, height: u64, distribution: BitcoinDistribution) {
+        self.cache.insert(height, distribution);
+
+        if self.cache.len() > self.snapshot_capacity {
+            if let Some(&oldest_height) = self.cache.keys().min() {
+                self.cache.remove(&oldest_height);
             }
-            
-            cumulative_addresses = new_cumulative;
         }
-        
-        // If we get here, return the maximum
-        distribution.ranges.iter()
-            .map(|r| r.max_btc)
-            .fold(0.0, |a, b| a.max(b))
     }
-    
-    /// Cache distribution data
-    #[allow(dead_code)]
-    pub fn cache_distribution(&mut self, key: String, distribution: BitcoinDistribution) {
-        self.cache.insert(key, distribution);
+
+    /// Get a stored snapshot by height
+    pub fn get_snapshot(&self, height: u64) -> Option<&BitcoinDistribution> {
+        self.cache.get(&height)
     }
-    
-    /// Get cached distribution
-    #[allow(dead_code)]
-    pub fn get_cached_distribution(&self, key: &str) -> Option<&BitcoinDistribution> {
-        self.cache.get(key)
+
+    /// List the heights of all stored snapshots, oldest first
+    pub fn available_heights(&self) -> Vec<u64> {
+        let mut heights: Vec<u64> = self.cache.keys().copied().collect();
+        heights.sort_unstable();
+        heights
     }
-    
-    /// Clear cache
-    #[allow(dead_code)]
-    pub fn clear_cache(&mut self) {
+
+    /// Clear all stored snapshots
+    pub fn clear_snapshots(&mut self) {
         self.cache.clear();
     }
+
+    /// Diff two stored snapshots, returning per-range address/BTC deltas plus a net
+    /// flow vector that carries the signed BTC surplus forward as bands are walked
+    /// low-to-high, i.e. how much wealth migrated past each band boundary.
+    pub fn distribution_flow(
+        &self,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<DistributionFlow, String> {
+        let from = self
+            .cache
+            .get(&from_height)
+            .ok_or_else(|| format!("No snapshot stored at height {}", from_height))?;
+        let to = self
+            .cache
+            .get(&to_height)
+            .ok_or_else(|| format!("No snapshot stored at height {}", to_height))?;
+
+        let mut from_ranges = from.ranges.clone();
+        let mut to_ranges = to.ranges.clone();
+        self.sort_ranges(&mut from_ranges);
+        self.sort_ranges(&mut to_ranges);
+
+        let band_count = from_ranges.len().max(to_ranges.len());
+        let mut ranges = Vec::with_capacity(band_count);
+        let mut net_migration_btc = Vec::with_capacity(band_count);
+        let mut carried_btc = 0.0;
+
+        for i in 0..band_count {
+            let from_range = from_ranges.get(i);
+            let to_range = to_ranges.get(i);
+
+            let (min_btc, max_btc) = match (from_range, to_range) {
+                (Some(f), _) => (f.min_btc, f.max_btc),
+                (_, Some(t)) => (t.min_btc, t.max_btc),
+                (None, None) => continue,
+            };
+
+            let address_count_delta = to_range.map(|r| r.address_count).unwrap_or(0) as i64
+                - from_range.map(|r| r.address_count).unwrap_or(0) as i64;
+            let total_btc_delta =
+                to_range.map(|r| r.total_btc).unwrap_or(0.0) - from_range.map(|r| r.total_btc).unwrap_or(0.0);
+
+            carried_btc += total_btc_delta;
+            net_migration_btc.push(carried_btc);
+
+            ranges.push(WealthRangeFlow {
+                min_btc,
+                max_btc,
+                address_count_delta,
+                total_btc_delta,
+            });
+        }
+
+        Ok(DistributionFlow {
+            from_height,
+            to_height,
+            ranges,
+            net_migration_btc,
+        })
+    }
 }
 
 impl Default for DataProcessor {
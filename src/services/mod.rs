@@ -0,0 +1,6 @@
+pub mod bitcoin_api;
+pub mod data_processor;
+pub mod percentile_calculator;
+pub mod price_source;
+pub mod price_stream;
+pub mod streaming_percentile;
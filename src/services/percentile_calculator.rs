@@ -1,20 +1,48 @@
-use crate::types::bitcoin::{BitcoinDistribution, PercentileResult, WealthCategory};
+use crate::types::bitcoin::{BitcoinDistribution, DistributionSnapshot, PercentileDriftPoint, PercentileResult, PortfolioPercentileResult, WealthCategory};
 use crate::services::data_processor::DataProcessor;
+use crate::services::price_source::{fetch_live_price, PriceSource, StaticPrice};
 use crate::utils::validators::validate_bitcoin_amount;
 use crate::utils::formatters::{format_large_number, format_rank};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
 use web_sys::console;
 
 pub struct PercentileCalculator {
     data_processor: DataProcessor,
+    price_source: Box<dyn PriceSource>,
+    price_spread: f64,
 }
 
 impl PercentileCalculator {
     pub fn new() -> Self {
+        Self::new_with_price_source(Box::new(StaticPrice::default()), 0.0)
+    }
+
+    /// Construct with a custom BTC/USD price source and a percentage spread applied to its spot
+    /// price (positive for a conservative/high valuation, negative for an aggressive/low one),
+    /// following the same bid/ask spread convention used by automated market makers
+    pub fn new_with_price_source(price_source: Box<dyn PriceSource>, price_spread: f64) -> Self {
         Self {
             data_processor: DataProcessor::new(),
+            price_source,
+            price_spread,
         }
     }
-    
+
+    /// Construct with the live BTC/USD price fetched from a public ticker, with `price_spread`
+    /// applied on top
+    #[allow(dead_code)]
+    pub async fn new_with_live_price(price_spread: f64) -> Result<Self, String> {
+        let price = fetch_live_price().await?;
+        Ok(Self::new_with_price_source(Box::new(StaticPrice::new(price)), price_spread))
+    }
+
+    /// The BTC/USD price actually used for dollar-value estimates, after applying `price_spread`
+    /// to the price source's spot price
+    fn effective_price(&self) -> f64 {
+        self.price_source.spot_usd() * (1.0 + self.price_spread / 100.0)
+    }
+
     /// Calculate detailed percentile information for a user's Bitcoin amount
     pub fn calculate_user_percentile(
         &self,
@@ -74,63 +102,119 @@ impl PercentileCalculator {
         range: &crate::types::bitcoin::WealthRange,
         distribution: &BitcoinDistribution,
     ) -> f64 {
+        self.calculate_percentile_in_range_decimal(user_amount, range, distribution)
+            .to_f64()
+            .unwrap_or(0.0)
+    }
+
+    /// Decimal-precision accessor for `calculate_percentile_in_range`: the cumulative
+    /// address-count and position arithmetic runs on `rust_decimal::Decimal` throughout
+    /// (rather than `f64` truncated with `as u64`), so repeated runs on the same distribution
+    /// always return a byte-identical percentile
+    fn calculate_percentile_in_range_decimal(
+        &self,
+        user_amount: f64,
+        range: &crate::types::bitcoin::WealthRange,
+        distribution: &BitcoinDistribution,
+    ) -> Decimal {
         // Calculate how many addresses are below this range
         let addresses_below_range = distribution.ranges.iter()
             .filter(|r| r.max_btc <= range.min_btc)
             .map(|r| r.address_count)
             .sum::<u64>();
-        
+
         // Calculate position within the range
         let position_in_range = if range.max_btc == f64::INFINITY {
-            // For the highest range, assume exponential distribution
-            self.calculate_position_in_infinite_range(user_amount, range)
+            // For the highest range, fit a Pareto tail to the data (inherently floating-point,
+            // since it involves a fractional exponent)
+            Self::to_decimal(self.calculate_position_in_infinite_range(user_amount, range, distribution))
         } else {
             // Linear interpolation within the range
-            (user_amount - range.min_btc) / (range.max_btc - range.min_btc)
+            Self::decimal_position(user_amount, range.min_btc, range.max_btc)
         };
-        
-        let addresses_below_in_range = (range.address_count as f64 * position_in_range) as u64;
-        let total_addresses_below = addresses_below_range + addresses_below_in_range;
-        
+
+        let addresses_below_in_range = (Decimal::from(range.address_count) * position_in_range).floor();
+        let total_addresses_below = Decimal::from(addresses_below_range) + addresses_below_in_range;
+
         // Calculate percentile
-        (total_addresses_below as f64 / distribution.total_addresses as f64) * 100.0
+        total_addresses_below
+            .checked_div(Decimal::from(distribution.total_addresses))
+            .unwrap_or(Decimal::ZERO)
+            * Decimal::from(100)
     }
-    
+
+    /// Convert an `f64` to `Decimal`, falling back to zero for values `Decimal` can't represent
+    /// (e.g. `NaN` or `infinity`)
+    fn to_decimal(value: f64) -> Decimal {
+        Decimal::from_f64(value).unwrap_or(Decimal::ZERO)
+    }
+
+    /// Fractional position of `value` between `min` and `max`, computed on `Decimal` to avoid
+    /// the rounding error `f64` division accumulates across many ranges
+    fn decimal_position(value: f64, min: f64, max: f64) -> Decimal {
+        let min_d = Self::to_decimal(min);
+        let max_d = Self::to_decimal(max);
+        let span = max_d - min_d;
+
+        if span.is_zero() {
+            Decimal::ZERO
+        } else {
+            (Self::to_decimal(value) - min_d).checked_div(span).unwrap_or(Decimal::ZERO)
+        }
+    }
+
     /// Calculate position within an infinite range (for whale category)
-    fn calculate_position_in_infinite_range(&self, user_amount: f64, range: &crate::types::bitcoin::WealthRange) -> f64 {
-        // Use logarithmic scale for infinite range
-        // Assume the range follows a power law distribution
-        let log_min = range.min_btc.ln();
-        let log_user = user_amount.ln();
-        
-        // Estimate the "effective maximum" based on the distribution
-        let effective_max = range.min_btc * 100.0; // Assume 100x as effective maximum
-        let log_max = effective_max.ln();
-        
-        ((log_user - log_min) / (log_max - log_min)).min(0.99) // Cap at 99%
+    ///
+    /// Models holdings above `range.min_btc` as Pareto-distributed with survival function
+    /// `P(X > x) = (min_btc / x)^alpha`, so the fraction of the range below `user_amount` is
+    /// `1 - (min_btc / user_amount)^alpha`.
+    fn calculate_position_in_infinite_range(&self, user_amount: f64, range: &crate::types::bitcoin::WealthRange, distribution: &BitcoinDistribution) -> f64 {
+        let alpha = self.pareto_alpha(range, distribution);
+        (1.0 - (range.min_btc / user_amount).powf(alpha)).min(0.99) // Cap at 99%
+    }
+
+    /// Fit the Pareto exponent `alpha` for an unbounded range from its known mean:
+    /// a Pareto(min_btc, alpha) distribution has `mean = alpha*min_btc/(alpha-1)`, so
+    /// `alpha = mean / (mean - min_btc)`. Falls back to a default alpha when the data-derived
+    /// mean isn't usable (no addresses, or a mean at or below `min_btc`).
+    fn pareto_alpha(&self, range: &crate::types::bitcoin::WealthRange, distribution: &BitcoinDistribution) -> f64 {
+        const DEFAULT_ALPHA: f64 = 1.5;
+
+        if range.address_count == 0 {
+            return DEFAULT_ALPHA;
+        }
+
+        let range_supply = range.percentage_of_supply / 100.0 * distribution.total_supply;
+        let mean = range_supply / range.address_count as f64;
+
+        if mean > range.min_btc {
+            mean / (mean - range.min_btc)
+        } else {
+            DEFAULT_ALPHA
+        }
     }
     
     /// Calculate how many addresses have less Bitcoin
     fn calculate_addresses_below(&self, user_amount: f64, distribution: &BitcoinDistribution) -> u64 {
-        let mut addresses_below = 0u64;
-        
+        let mut addresses_below = Decimal::ZERO;
+
         for range in &distribution.ranges {
             if range.max_btc <= user_amount {
                 // Entire range is below user amount
-                addresses_below += range.address_count;
+                addresses_below += Decimal::from(range.address_count);
             } else if range.min_btc < user_amount && user_amount < range.max_btc {
                 // User amount is within this range
                 let position = if range.max_btc == f64::INFINITY {
-                    self.calculate_position_in_infinite_range(user_amount, range)
+                    Self::to_decimal(self.calculate_position_in_infinite_range(user_amount, range, distribution))
                 } else {
-                    (user_amount - range.min_btc) / (range.max_btc - range.min_btc)
+                    Self::decimal_position(user_amount, range.min_btc, range.max_btc)
                 };
-                addresses_below += (range.address_count as f64 * position) as u64;
+                addresses_below += (Decimal::from(range.address_count) * position).floor();
             }
             // If range.min_btc >= user_amount, skip (entire range is above)
         }
-        
-        addresses_below
+
+        addresses_below.floor().to_u64().unwrap_or(0)
     }
     
     /// Determine wealth category based on Bitcoin amount
@@ -178,10 +262,11 @@ impl PercentileCalculator {
             metrics.insert(format!("years_to_accumulate_at_{}_btc_per_day", rate), years);
         }
         
-        // Dollar value estimates (using approximate price)
-        let btc_price = 50000.0; // Approximate BTC price in USD
+        // Dollar value estimates, using the configured price source and spread
+        let btc_price = self.effective_price();
         let dollar_value = user_amount * btc_price;
         metrics.insert("estimated_usd_value".to_string(), dollar_value);
+        metrics.insert("btc_price_used".to_string(), btc_price);
         
         metrics
     }
@@ -208,50 +293,112 @@ impl PercentileCalculator {
     fn calculate_amount_at_percentile(&self, percentile: f64, distribution: &BitcoinDistribution) -> f64 {
         let mut sorted_ranges = distribution.ranges.clone();
         self.data_processor.sort_ranges(&mut sorted_ranges);
-        
-        let mut cumulative_addresses = 0.0;
-        
+
+        let percentile_d = Self::to_decimal(percentile);
+        let mut cumulative_addresses = Decimal::ZERO;
+
         for range in sorted_ranges {
-            let new_cumulative = cumulative_addresses + range.percentage_of_addresses;
-            
-            if new_cumulative >= percentile {
+            let range_share = Self::to_decimal(range.percentage_of_addresses);
+            let new_cumulative = cumulative_addresses + range_share;
+
+            if new_cumulative >= percentile_d {
                 // Interpolate within the range
-                let remaining = percentile - cumulative_addresses;
-                let position = remaining / range.percentage_of_addresses;
-                
+                let remaining = percentile_d - cumulative_addresses;
+                let position = if range_share.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    remaining.checked_div(range_share).unwrap_or(Decimal::ZERO)
+                };
+
                 if range.max_btc == f64::INFINITY {
-                    // For infinite range, use logarithmic interpolation
-                    let log_min = range.min_btc.ln();
-                    let effective_max = range.min_btc * 100.0; // Assume 100x span
-                    let log_max = effective_max.ln();
-                    let log_amount = log_min + position * (log_max - log_min);
-                    return log_amount.exp();
+                    // Invert the Pareto survival function fit in `pareto_alpha` (inherently
+                    // floating-point, since it involves a fractional exponent):
+                    // x = min_btc * (1 - q)^(-1/alpha)
+                    let alpha = self.pareto_alpha(&range, distribution);
+                    let position_f64 = position.to_f64().unwrap_or(0.0);
+                    return range.min_btc * (1.0 - position_f64).powf(-1.0 / alpha);
                 } else {
-                    return range.min_btc + position * (range.max_btc - range.min_btc);
+                    let min_d = Self::to_decimal(range.min_btc);
+                    let max_d = Self::to_decimal(range.max_btc);
+                    return (min_d + position * (max_d - min_d)).to_f64().unwrap_or(range.min_btc);
                 }
             }
-            
+
             cumulative_addresses = new_cumulative;
         }
-        
-        // If we get here, return the maximum available
-        distribution.ranges.iter()
-            .map(|r| if r.max_btc == f64::INFINITY { r.min_btc * 100.0 } else { r.max_btc })
-            .fold(0.0, |a, b| a.max(b))
+
+        // If we get here, the cumulative share never quite reached `percentile` (tolerated by
+        // `validate_totals`'s 1% slack) before the ranges ran out, so the target sits at or above
+        // the open-ended top range. Invert the same Pareto tail fit used in the loop above, at the
+        // same 99% cap `calculate_position_in_infinite_range` uses, instead of the old
+        // hard-coded `min_btc * 100.0` stand-in.
+        if let Some(top_range) = distribution.ranges.iter().find(|r| r.max_btc == f64::INFINITY) {
+            let alpha = self.pareto_alpha(top_range, distribution);
+            return top_range.min_btc * (1.0 - 0.99_f64).powf(-1.0 / alpha);
+        }
+
+        distribution.ranges.iter().map(|r| r.max_btc).fold(0.0, |a, b| a.max(b))
     }
-    
+
+    /// Decimal-precision accessor: computes the same percentile as `calculate_user_percentile`,
+    /// but returns the raw `Decimal` before any lossy conversion to `f64`, so repeated runs on
+    /// the same inputs are guaranteed byte-identical — useful for a public "your rank" display
+    /// that must stay reproducible.
+    #[allow(dead_code)]
+    pub fn calculate_user_percentile_decimal(
+        &self,
+        user_amount: f64,
+        distribution: &BitcoinDistribution,
+    ) -> Result<Decimal, String> {
+        validate_bitcoin_amount(user_amount)?;
+        self.data_processor.validate_distribution(distribution)?;
+
+        let range = self
+            .data_processor
+            .find_range_for_amount(user_amount, distribution)
+            .ok_or_else(|| "User amount does not fit in any distribution range".to_string())?;
+
+        Ok(self.calculate_percentile_in_range_decimal(user_amount, range, distribution))
+    }
+
+    /// Combine a portfolio's labeled holdings (see `crate::types::portfolio::PortfolioEntry`)
+    /// into a single percentile calculation on their summed BTC amount, plus each entry's share
+    /// of that sum
+    pub fn aggregate_portfolio_percentile(
+        &self,
+        amounts: &[f64],
+        distribution: &BitcoinDistribution,
+    ) -> Result<PortfolioPercentileResult, String> {
+        if amounts.is_empty() {
+            return Err("Portfolio has no holdings to aggregate".to_string());
+        }
+
+        let total: f64 = amounts.iter().sum();
+        let combined = self.calculate_user_percentile(total, distribution)?;
+
+        let contribution_shares = amounts
+            .iter()
+            .map(|amount| if total > 0.0 { amount / total } else { 0.0 })
+            .collect();
+
+        Ok(PortfolioPercentileResult {
+            combined,
+            contribution_shares,
+        })
+    }
+
     /// Calculate wealth concentration analysis
     pub fn calculate_wealth_concentration(&self, distribution: &BitcoinDistribution) -> Result<std::collections::HashMap<String, f64>, String> {
         self.data_processor.validate_distribution(distribution)?;
-        
+
         let mut concentration = std::collections::HashMap::new();
-        
+
         // Calculate Gini coefficient
         let stats = self.data_processor.calculate_statistics(distribution);
         if let Some(gini) = stats.get("gini_coefficient") {
             concentration.insert("gini_coefficient".to_string(), *gini);
         }
-        
+
         // Calculate concentration ratios
         let concentration_levels = vec![0.1, 0.5, 1.0, 5.0, 10.0, 25.0];
         for level in concentration_levels {
@@ -259,14 +406,102 @@ impl PercentileCalculator {
             let wealth_share = self.calculate_top_percent_wealth(level, distribution);
             concentration.insert(key, wealth_share);
         }
-        
+
         // Calculate Herfindahl-Hirschman Index (HHI) for concentration
         let hhi = self.calculate_hhi(distribution);
         concentration.insert("hhi_index".to_string(), hhi);
-        
+
+        // Palma ratio: wealth share of the top 10% over the share of the bottom 40%
+        concentration.insert("palma_ratio".to_string(), self.calculate_palma_ratio(distribution));
+
+        // Theil-T index of wealth inequality across the distribution's ranges
+        concentration.insert("theil_index".to_string(), self.calculate_theil_index(distribution));
+
+        // Atkinson index with a moderate inequality-aversion parameter
+        concentration.insert(
+            "atkinson_index".to_string(),
+            self.calculate_atkinson_index(distribution, 0.5),
+        );
+
         Ok(concentration)
     }
-    
+
+    /// Build the Lorenz curve directly from the distribution's ranges, for charting: cumulative
+    /// fraction of addresses (x) against cumulative fraction of total BTC held (y), sorted
+    /// ascending by `min_btc` and starting at `(0.0, 0.0)`
+    pub fn lorenz_curve(&self, distribution: &BitcoinDistribution) -> Vec<(f64, f64)> {
+        self.data_processor.lorenz_curve_points(distribution)
+    }
+
+    /// Gini coefficient computed by trapezoidal integration over the Lorenz curve:
+    /// `gini = 1 - Σ (x_i - x_{i-1})(y_i + y_{i-1})`
+    #[allow(dead_code)]
+    pub fn gini_from_lorenz_curve(&self, distribution: &BitcoinDistribution) -> f64 {
+        let points = self.lorenz_curve(distribution);
+        let area: f64 = points
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0) * (pair[1].1 + pair[0].1))
+            .sum();
+        (1.0 - area).clamp(0.0, 1.0)
+    }
+
+    /// Palma ratio: the wealth share held by the top 10% of addresses divided by the share
+    /// held by the bottom 40%
+    fn calculate_palma_ratio(&self, distribution: &BitcoinDistribution) -> f64 {
+        let top_10_share = self.calculate_top_percent_wealth(10.0, distribution);
+        let bottom_40_share = 100.0 - self.calculate_top_percent_wealth(60.0, distribution);
+
+        if bottom_40_share > 0.0 {
+            top_10_share / bottom_40_share
+        } else {
+            0.0
+        }
+    }
+
+    /// Grouped Theil-T index: `Σ s_i * ln(s_i / p_i)` over the distribution's ranges, where
+    /// `s_i` is a range's wealth share and `p_i` its population share. Ranges with zero share
+    /// are skipped, since they contribute nothing to the index and `ln(0)` is undefined.
+    fn calculate_theil_index(&self, distribution: &BitcoinDistribution) -> f64 {
+        distribution
+            .ranges
+            .iter()
+            .filter(|range| range.percentage_of_supply > 0.0 && range.percentage_of_addresses > 0.0)
+            .map(|range| {
+                let wealth_share = range.percentage_of_supply / 100.0;
+                let population_share = range.percentage_of_addresses / 100.0;
+                wealth_share * (wealth_share / population_share).ln()
+            })
+            .sum()
+    }
+
+    /// Atkinson index of inequality with inequality-aversion parameter `epsilon`, treating each
+    /// range as a group with mean holding `range.total_btc / range.address_count`:
+    /// `A = 1 - (Σ p_i * (y_i/ȳ)^(1-ε))^(1/(1-ε))`, or `A = 1 - exp(Σ p_i * ln(y_i/ȳ))` at `ε = 1`
+    fn calculate_atkinson_index(&self, distribution: &BitcoinDistribution, epsilon: f64) -> f64 {
+        if distribution.total_addresses == 0 || distribution.total_supply <= 0.0 {
+            return 0.0;
+        }
+
+        let mean = distribution.total_supply / distribution.total_addresses as f64;
+        let groups = distribution.ranges.iter().filter(|range| range.address_count > 0).map(|range| {
+            let population_share = range.address_count as f64 / distribution.total_addresses as f64;
+            let group_mean = range.total_btc / range.address_count as f64;
+            (population_share, group_mean)
+        });
+
+        if (epsilon - 1.0).abs() < f64::EPSILON {
+            let weighted_log_mean: f64 = groups
+                .map(|(p, y)| p * (y / mean).ln())
+                .sum();
+            1.0 - weighted_log_mean.exp()
+        } else {
+            let weighted_power_mean: f64 = groups
+                .map(|(p, y)| p * (y / mean).powf(1.0 - epsilon))
+                .sum();
+            1.0 - weighted_power_mean.powf(1.0 / (1.0 - epsilon))
+        }
+    }
+
     /// Calculate what percentage of total wealth is held by top X% of addresses
     fn calculate_top_percent_wealth(&self, top_percent: f64, distribution: &BitcoinDistribution) -> f64 {
         let mut sorted_ranges = distribution.ranges.clone();
@@ -304,12 +539,62 @@ impl PercentileCalculator {
             .sum::<f64>() * 10000.0 // Scale to 0-10000 range
     }
     
-    /// Generate a wealth report for a user
+    /// Bitcoin amount at a given percentile for a distribution, exposed for historical drift
+    /// tracking and other external threshold lookups
+    #[allow(dead_code)]
+    pub fn amount_at_percentile(&self, percentile: f64, distribution: &BitcoinDistribution) -> f64 {
+        self.calculate_amount_at_percentile(percentile, distribution)
+    }
+
+    /// Track how a user's standing moves across a series of dated distribution snapshots.
+    ///
+    /// For each snapshot, returns the percentile/rank from `calculate_user_percentile`, plus how
+    /// far percentile and rank have drifted from the first snapshot, and the "treadmill" amount —
+    /// how much additional BTC would have been required at that snapshot to hold the same
+    /// percentile as the first one (inverted via `calculate_amount_at_percentile`).
     #[allow(dead_code)]
-    pub fn generate_wealth_report(&self, user_amount: f64, distribution: &BitcoinDistribution) -> Result<String, String> {
+    pub fn calculate_percentile_drift(
+        &self,
+        user_amount: f64,
+        snapshots: &[DistributionSnapshot],
+    ) -> Result<Vec<PercentileDriftPoint>, String> {
+        let mut points = Vec::with_capacity(snapshots.len());
+        let mut baseline: Option<(f64, u64)> = None;
+
+        for snapshot in snapshots {
+            let result = self.calculate_user_percentile(user_amount, &snapshot.distribution)?;
+            let (baseline_percentile, baseline_rank) =
+                *baseline.get_or_insert((result.percentile, result.rank));
+
+            let treadmill_amount =
+                self.amount_at_percentile(baseline_percentile, &snapshot.distribution) - user_amount;
+
+            points.push(PercentileDriftPoint {
+                label: snapshot.label.clone(),
+                timestamp: snapshot.distribution.timestamp,
+                percentile: result.percentile,
+                rank: result.rank,
+                percentile_change: result.percentile - baseline_percentile,
+                rank_change: result.rank as i64 - baseline_rank as i64,
+                treadmill_amount,
+            });
+        }
+
+        Ok(points)
+    }
+
+    /// Generate a wealth report for a user, with an optional historical drift section appended
+    /// when `snapshots` is given
+    #[allow(dead_code)]
+    pub fn generate_wealth_report(
+        &self,
+        user_amount: f64,
+        distribution: &BitcoinDistribution,
+        snapshots: Option<&[DistributionSnapshot]>,
+    ) -> Result<String, String> {
         let result = self.calculate_user_percentile(user_amount, distribution)?;
         let thresholds = self.calculate_percentile_thresholds(distribution)?;
-        
+
         let mut report = String::new();
         
         report.push_str(&format!("🏆 Bitcoin Wealth Report\n"));
@@ -338,7 +623,26 @@ impl PercentileCalculator {
         for (percentile, amount) in thresholds {
             report.push_str(&format!("• {:.1}th percentile: {:.8} BTC\n", percentile, amount));
         }
-        
+
+        if let Some(snapshots) = snapshots {
+            let drift = self.calculate_percentile_drift(user_amount, snapshots)?;
+            if !drift.is_empty() {
+                report.push_str(&format!("\n"));
+                report.push_str(&format!("📈 Historical Standing:\n"));
+                for point in &drift {
+                    report.push_str(&format!(
+                        "• {}: {:.2}th percentile ({:+.2} vs first), rank {} ({:+} vs first), would need {:+.8} more BTC to match your original percentile\n",
+                        point.label,
+                        point.percentile,
+                        point.percentile_change,
+                        format_rank(point.rank),
+                        point.rank_change,
+                        point.treadmill_amount
+                    ));
+                }
+            }
+        }
+
         Ok(report)
     }
 }
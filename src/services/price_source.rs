@@ -0,0 +1,34 @@
+use crate::services::bitcoin_api::BitcoinApiService;
+
+/// Source of the current BTC/USD spot price used for dollar-value estimates
+pub trait PriceSource {
+    fn spot_usd(&self) -> f64;
+}
+
+/// A fixed price, used both as the default fallback and to hold an already-fetched live price
+pub struct StaticPrice {
+    price: f64,
+}
+
+impl StaticPrice {
+    pub fn new(price: f64) -> Self {
+        Self { price }
+    }
+}
+
+impl PriceSource for StaticPrice {
+    fn spot_usd(&self) -> f64 {
+        self.price
+    }
+}
+
+impl Default for StaticPrice {
+    fn default() -> Self {
+        Self::new(50_000.0) // Same fallback previously hard-coded in calculate_comparison_metrics
+    }
+}
+
+/// Fetch the current BTC/USD spot price from a public ticker, for building a `StaticPrice`
+pub async fn fetch_live_price() -> Result<f64, String> {
+    BitcoinApiService::new().fetch_bitcoin_price().await
+}
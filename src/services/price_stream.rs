@@ -0,0 +1,137 @@
+use crate::services::price_source::PriceSource;
+use gloo_timers::future::TimeoutFuture;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{CloseEvent, MessageEvent, WebSocket};
+
+const KRAKEN_TICKER_WS_URL: &str = "wss://ws.kraken.com";
+const XBT_USD_PAIR: &str = "XBT/USD";
+const INITIAL_RECONNECT_DELAY_MS: u32 = 1_000;
+const MAX_RECONNECT_DELAY_MS: u32 = 30_000;
+
+/// Kraken's public ticker feed has no common tag to dispatch on: system/subscription events
+/// arrive as a JSON object with an `event` field, while ticker updates arrive as a bare
+/// `[channelID, data, channelName, pair]` array. `#[serde(untagged)]` tries each variant in
+/// order until one parses, which is enough to tell the two shapes apart.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum KrakenMessage {
+    Ticker(KrakenTickerFrame),
+    Event(KrakenEvent),
+}
+
+/// A `systemStatus`/`subscriptionStatus`/... event; only its discriminant is of interest here
+#[derive(Debug, Clone, Deserialize)]
+struct KrakenEvent {
+    #[allow(dead_code)]
+    event: String,
+}
+
+/// A ticker update frame: `[channelID, data, channelName, pair]`
+#[derive(Debug, Clone, Deserialize)]
+struct KrakenTickerFrame(#[allow(dead_code)] u64, KrakenTickerData, #[allow(dead_code)] String, #[allow(dead_code)] String);
+
+/// The fields of a ticker frame's `data` object that this app cares about
+#[derive(Debug, Clone, Deserialize)]
+struct KrakenTickerData {
+    /// Last trade closed: `[price, lot volume]`, both encoded as strings
+    c: (String, String),
+}
+
+/// A live BTC/USD spot price kept up to date by a Kraken ticker WebSocket subscription, so the
+/// comparison UI can show a price that updates in real time instead of re-polling. Reads are
+/// always non-blocking (`spot_usd` just reads the last price seen); the socket reconnects with
+/// exponential backoff if it drops.
+pub struct LivePriceStream {
+    price: Rc<RefCell<f64>>,
+    // Held only to keep the socket (and its closures, via its own internal refs) alive for the
+    // lifetime of this stream; never read directly.
+    #[allow(dead_code)]
+    socket_slot: Rc<RefCell<Option<WebSocket>>>,
+}
+
+impl LivePriceStream {
+    /// Open the ticker subscription, seeding `initial_price` until the first tick arrives
+    pub fn connect(initial_price: f64) -> Self {
+        let price = Rc::new(RefCell::new(initial_price));
+        let socket_slot: Rc<RefCell<Option<WebSocket>>> = Rc::new(RefCell::new(None));
+
+        open_socket(price.clone(), socket_slot.clone(), INITIAL_RECONNECT_DELAY_MS);
+
+        Self { price, socket_slot }
+    }
+}
+
+impl PriceSource for LivePriceStream {
+    fn spot_usd(&self) -> f64 {
+        *self.price.borrow()
+    }
+}
+
+/// Open one WebSocket connection, subscribe to the ticker channel once it's open, and update
+/// `price` as ticks arrive. On close, schedules a reconnect after `reconnect_delay_ms`, doubling
+/// the delay each time (capped at `MAX_RECONNECT_DELAY_MS`) so a persistently unreachable feed
+/// doesn't spin.
+fn open_socket(price: Rc<RefCell<f64>>, socket_slot: Rc<RefCell<Option<WebSocket>>>, reconnect_delay_ms: u32) {
+    let socket = match WebSocket::new(KRAKEN_TICKER_WS_URL) {
+        Ok(socket) => socket,
+        Err(_) => {
+            schedule_reconnect(price, socket_slot, reconnect_delay_ms);
+            return;
+        }
+    };
+
+    {
+        let socket_for_subscribe = socket.clone();
+        let onopen = Closure::<dyn Fn()>::new(move || {
+            let subscribe_message = serde_json::json!({
+                "event": "subscribe",
+                "pair": [XBT_USD_PAIR],
+                "subscription": { "name": "ticker" },
+            });
+            if let Ok(text) = serde_json::to_string(&subscribe_message) {
+                let _ = socket_for_subscribe.send_with_str(&text);
+            }
+        });
+        socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        onopen.forget();
+    }
+
+    {
+        let price = price.clone();
+        let onmessage = Closure::<dyn Fn(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(text) = event.data().as_string() else { return };
+            if let Ok(KrakenMessage::Ticker(frame)) = serde_json::from_str::<KrakenMessage>(&text) {
+                if let Ok(last_trade_price) = frame.1.c.0.parse::<f64>() {
+                    *price.borrow_mut() = last_trade_price;
+                }
+            }
+        });
+        socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        onmessage.forget();
+    }
+
+    {
+        let price = price.clone();
+        let socket_slot_for_close = socket_slot.clone();
+        let onclose = Closure::<dyn Fn(CloseEvent)>::new(move |_event: CloseEvent| {
+            schedule_reconnect(price.clone(), socket_slot_for_close.clone(), reconnect_delay_ms);
+        });
+        socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+        onclose.forget();
+    }
+
+    *socket_slot.borrow_mut() = Some(socket);
+}
+
+fn schedule_reconnect(price: Rc<RefCell<f64>>, socket_slot: Rc<RefCell<Option<WebSocket>>>, delay_ms: u32) {
+    let next_delay_ms = delay_ms.saturating_mul(2).min(MAX_RECONNECT_DELAY_MS);
+    spawn_local(async move {
+        TimeoutFuture::new(delay_ms).await;
+        open_socket(price, socket_slot, next_delay_ms);
+    });
+}
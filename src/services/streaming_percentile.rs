@@ -0,0 +1,216 @@
+use crate::types::bitcoin::{BitcoinDistribution, WealthRange};
+
+/// Online P² (piecewise-parabolic) quantile estimator for a single quantile `p` in `[0, 1]`.
+///
+/// Maintains five markers — their heights `q[0..5]`, actual positions `n[0..5]` and desired
+/// positions `n'[0..5]` — so a quantile can be tracked over an arbitrarily long stream of
+/// samples in constant memory, without ever storing the samples themselves. See Jain & Chlamtac,
+/// "The P² Algorithm for Dynamic Calculation of Quantiles and Histograms Without Storing
+/// Observations" (1985).
+pub struct P2Estimator {
+    p: f64,
+    count: usize,
+    heights: [f64; 5],
+    positions: [f64; 5],
+    desired_positions: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Estimator {
+    pub fn new(p: f64) -> Self {
+        Self {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired_positions: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    /// Feed one more sample into the estimator
+    pub fn observe(&mut self, value: f64) {
+        self.count += 1;
+
+        // Prime the five markers with the first five samples, sorted
+        if self.count <= 5 {
+            self.heights[self.count - 1] = value;
+            if self.count == 5 {
+                self.heights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            }
+            return;
+        }
+
+        // Find the cell containing `value`, extending an endpoint marker if it falls outside
+        let k = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[4] {
+            self.heights[4] = value;
+            3
+        } else {
+            (0..4)
+                .find(|&i| self.heights[i] <= value && value < self.heights[i + 1])
+                .unwrap_or(3)
+        };
+
+        // Increment the actual position of every marker above the containing cell
+        for position in self.positions.iter_mut().skip(k + 1) {
+            *position += 1.0;
+        }
+        for i in 0..5 {
+            self.desired_positions[i] += self.increments[i];
+        }
+
+        // Adjust the three interior markers toward their desired positions
+        for i in 1..4 {
+            let d = self.desired_positions[i] - self.positions[i];
+
+            if (d >= 1.0 && self.positions[i + 1] - self.positions[i] > 1.0)
+                || (d <= -1.0 && self.positions[i - 1] - self.positions[i] < -1.0)
+            {
+                let d = d.signum();
+                let predicted = self.parabolic_prediction(i, d);
+
+                self.heights[i] = if self.heights[i - 1] < predicted && predicted < self.heights[i + 1] {
+                    predicted
+                } else {
+                    self.linear_prediction(i, d)
+                };
+                self.positions[i] += d;
+            }
+        }
+    }
+
+    /// Parabolic (P²) prediction for marker `i`, nudged by `d` (`+1.0` or `-1.0`)
+    fn parabolic_prediction(&self, i: usize, d: f64) -> f64 {
+        let (q_prev, q, q_next) = (self.heights[i - 1], self.heights[i], self.heights[i + 1]);
+        let (n_prev, n, n_next) = (self.positions[i - 1], self.positions[i], self.positions[i + 1]);
+
+        q + d / (n_next - n_prev)
+            * ((n - n_prev + d) * (q_next - q) / (n_next - n)
+                + (n_next - n - d) * (q - q_prev) / (n - n_prev))
+    }
+
+    /// Linear fallback when the parabolic prediction isn't strictly monotonic between neighbors
+    fn linear_prediction(&self, i: usize, d: f64) -> f64 {
+        let j = (i as isize + d as isize) as usize;
+        self.heights[i] + d * (self.heights[j] - self.heights[i]) / (self.positions[j] - self.positions[i])
+    }
+
+    /// Current estimate of the tracked quantile
+    pub fn quantile(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else if self.count < 5 {
+            let mut sorted = self.heights[..self.count].to_vec();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let index = (((self.count - 1) as f64) * self.p).round() as usize;
+            sorted[index.min(sorted.len() - 1)]
+        } else {
+            self.heights[2]
+        }
+    }
+}
+
+/// Tracks the same set of percentile thresholds as
+/// `PercentileCalculator::calculate_percentile_thresholds`, fed one address balance at a time in
+/// constant memory via `P2Estimator`, so thresholds can be built from a raw on-chain balance
+/// stream without ever materializing the full dataset.
+pub struct StreamingPercentileTracker {
+    estimators: Vec<(f64, P2Estimator)>,
+    count: u64,
+    sum: f64,
+}
+
+impl StreamingPercentileTracker {
+    pub fn new() -> Self {
+        let percentiles = [1.0, 5.0, 10.0, 25.0, 50.0, 75.0, 90.0, 95.0, 99.0, 99.9];
+        Self {
+            estimators: percentiles
+                .iter()
+                .map(|&percentile| (percentile, P2Estimator::new(percentile / 100.0)))
+                .collect(),
+            count: 0,
+            sum: 0.0,
+        }
+    }
+
+    /// Feed one more address balance into every tracked quantile
+    pub fn observe(&mut self, balance: f64) {
+        self.count += 1;
+        self.sum += balance;
+        for (_, estimator) in &mut self.estimators {
+            estimator.observe(balance);
+        }
+    }
+
+    /// Current threshold estimates, in the same `(percentile, amount)` shape as
+    /// `PercentileCalculator::calculate_percentile_thresholds`
+    pub fn thresholds(&self) -> Vec<(f64, f64)> {
+        self.estimators
+            .iter()
+            .map(|(percentile, estimator)| (*percentile, estimator.quantile()))
+            .collect()
+    }
+
+    /// Build a synthetic `BitcoinDistribution` from the tracked thresholds, so the streamed
+    /// estimate can seed the existing percentile and comparison APIs. Each bucket between
+    /// consecutive thresholds is assigned the trapezoid-average balance of its bounds, then all
+    /// buckets are rescaled so their total matches the actual observed sum.
+    pub fn to_distribution(&self, timestamp: u64) -> BitcoinDistribution {
+        let thresholds = self.thresholds();
+
+        let mut boundaries = vec![0.0];
+        boundaries.extend(thresholds.iter().map(|(_, amount)| *amount));
+        boundaries.push(f64::INFINITY);
+
+        let mut percentiles = vec![0.0];
+        percentiles.extend(thresholds.iter().map(|(percentile, _)| *percentile));
+        percentiles.push(100.0);
+
+        let mut ranges = Vec::with_capacity(boundaries.len() - 1);
+        let mut raw_btc_estimates = Vec::with_capacity(boundaries.len() - 1);
+
+        for i in 0..boundaries.len() - 1 {
+            let min_btc = boundaries[i];
+            let max_btc = boundaries[i + 1];
+            let percentage_of_addresses = percentiles[i + 1] - percentiles[i];
+            let address_count = ((percentage_of_addresses / 100.0) * self.count as f64).round() as u64;
+
+            let representative = if max_btc.is_finite() { (min_btc + max_btc) / 2.0 } else { min_btc };
+            raw_btc_estimates.push(representative * address_count as f64);
+
+            ranges.push(WealthRange {
+                min_btc,
+                max_btc,
+                address_count,
+                total_btc: 0.0,
+                percentage_of_addresses,
+                percentage_of_supply: 0.0,
+            });
+        }
+
+        let raw_total: f64 = raw_btc_estimates.iter().sum();
+        let scale = if raw_total > 0.0 { self.sum / raw_total } else { 0.0 };
+
+        for (range, raw_btc) in ranges.iter_mut().zip(raw_btc_estimates.iter()) {
+            range.total_btc = raw_btc * scale;
+            range.percentage_of_supply = if self.sum > 0.0 { range.total_btc / self.sum * 100.0 } else { 0.0 };
+        }
+
+        BitcoinDistribution {
+            ranges,
+            total_addresses: self.count,
+            total_supply: self.sum,
+            timestamp,
+            data_source: "streaming_p2_estimation".to_string(),
+        }
+    }
+}
+
+impl Default for StreamingPercentileTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
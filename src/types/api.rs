@@ -36,7 +36,6 @@ pub struct ApiError {
 }
 
 impl<T> ApiResponse<T> {
-    #[allow(dead_code)]
     pub fn success(data: T) -> Self {
         Self {
             success: true,
@@ -45,8 +44,7 @@ impl<T> ApiResponse<T> {
             timestamp: js_sys::Date::now() as u64,
         }
     }
-    
-    #[allow(dead_code)]
+
     pub fn error(message: String) -> Self {
         Self {
             success: false,
@@ -57,14 +55,12 @@ impl<T> ApiResponse<T> {
     }
 }
 
-/// Request configuration for API calls
+/// Request configuration for API calls, consumed by
+/// `BitcoinApiService::fetch_bitinfocharts_with_config` for its retry/backoff/timeout policy.
 #[derive(Debug, Clone)]
 pub struct ApiConfig {
-    #[allow(dead_code)]
     pub base_url: String,
-    #[allow(dead_code)]
     pub timeout_ms: u32,
-    #[allow(dead_code)]
     pub retry_count: u32,
     #[allow(dead_code)]
     pub api_key: Option<String>,
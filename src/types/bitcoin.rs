@@ -1,3 +1,5 @@
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -47,6 +49,37 @@ pub struct PercentileResult {
     pub comparison_metrics: HashMap<String, f64>,
 }
 
+/// Result of combining a portfolio's labeled holdings into one percentile calculation: the
+/// combined result for their summed BTC amount, plus each entry's share of that sum (aligned
+/// by index with the `amounts` slice passed to `PercentileCalculator::aggregate_portfolio_percentile`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioPercentileResult {
+    pub combined: PercentileResult,
+    pub contribution_shares: Vec<f64>,
+}
+
+/// A labeled, dated `BitcoinDistribution`, for historical percentile-drift tracking
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DistributionSnapshot {
+    pub label: String,
+    pub distribution: BitcoinDistribution,
+}
+
+/// One point in a percentile-drift time series: a user's standing at a given snapshot, and how
+/// far it has moved relative to the first snapshot in the series
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PercentileDriftPoint {
+    pub label: String,
+    pub timestamp: u64,
+    pub percentile: f64,
+    pub rank: u64,
+    pub percentile_change: f64,
+    pub rank_change: i64,
+    /// How much additional BTC would have been required at this snapshot to hold the same
+    /// percentile as the first snapshot in the series
+    pub treadmill_amount: f64,
+}
+
 /// Comprehensive Bitcoin network statistics
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BitcoinStats {
@@ -63,6 +96,32 @@ pub struct BitcoinStats {
     pub concentration_ratios: HashMap<String, f64>,
 }
 
+/// Spot price input used to value a distribution in fiat terms
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceProvider {
+    pub currency: String,
+    pub spot_price: f64,
+}
+
+/// Fiat valuation of a single `WealthRange`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FiatWealthRange {
+    pub min_btc: f64,
+    pub max_btc: f64,
+    pub total_fiat: f64,
+}
+
+/// Fiat-denominated view of a `BitcoinDistribution`, derived at a given spot price
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FiatDistribution {
+    pub currency: String,
+    pub ranges: Vec<FiatWealthRange>,
+    pub total_supply_fiat: f64,
+    pub mean_amount_fiat: f64,
+    pub median_amount_fiat: f64,
+    pub effective_price: f64,
+}
+
 /// Wealth inequality metrics
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WealthInequalityMetrics {
@@ -141,41 +200,89 @@ impl BitcoinDistribution {
     fn estimate_median(&self) -> f64 {
         let target_addresses = self.total_addresses / 2;
         let mut cumulative_addresses = 0u64;
-        
+
         for range in &self.ranges {
             cumulative_addresses += range.address_count;
             if cumulative_addresses >= target_addresses {
-                // Estimate median within this range
-                return (range.min_btc + range.max_btc) / 2.0;
+                // Estimate median within this range; an open-ended top range has no finite
+                // midpoint, so fall back to its floor instead of producing infinity
+                return if range.max_btc == f64::INFINITY {
+                    range.min_btc
+                } else {
+                    (range.min_btc + range.max_btc) / 2.0
+                };
             }
         }
-        
+
         0.0
     }
-    
+
+    /// Exact Gini coefficient, integrating the Lorenz curve over the pre-binned ranges rather
+    /// than the coarser approximation this used to make.
+    ///
+    /// Ranges are sorted ascending by representative balance (their mean, since wealth within a
+    /// bin is pre-aggregated and treated as concentrated at that mean; an open-ended top range
+    /// uses its floor instead to keep the representative finite), then walked to accumulate the
+    /// cumulative address fraction `p_i` and cumulative wealth fraction `L_i`. The area under the
+    /// curve is the trapezoidal sum `Σ (p_i - p_{i-1}) * (L_i + L_{i-1}) / 2`, and
+    /// `Gini = 1 - 2A`, clamped to `[0, 1]`. All accumulation runs on `Decimal` rather than `f64`
+    /// so the result doesn't drift between runs on the same distribution.
     #[allow(dead_code)]
     fn calculate_gini_coefficient(&self) -> f64 {
-        // Simplified Gini coefficient calculation
-        // In a real implementation, this would be more sophisticated
-        let mut total_area = 0.0;
-        let mut _cumulative_addresses = 0.0;
-        let mut cumulative_wealth = 0.0;
-        
-        for range in &self.ranges {
-            let address_proportion = range.address_count as f64 / self.total_addresses as f64;
-            let wealth_proportion = range.total_btc / self.total_supply;
-            
-            total_area += address_proportion * (cumulative_wealth + wealth_proportion / 2.0);
-            
-            _cumulative_addresses += address_proportion;
-            cumulative_wealth += wealth_proportion;
+        if self.total_addresses == 0 {
+            return 0.0;
         }
-        
-        // Gini = 1 - 2 * area_under_lorenz_curve
-        (1.0 - 2.0 * total_area).max(0.0).min(1.0)
+
+        let representative = |range: &WealthRange| {
+            if range.max_btc == f64::INFINITY {
+                range.min_btc
+            } else {
+                (range.min_btc + range.max_btc) / 2.0
+            }
+        };
+
+        let mut ranges_sorted: Vec<&WealthRange> =
+            self.ranges.iter().filter(|range| range.address_count > 0).collect();
+        ranges_sorted.sort_by(|a, b| representative(a).partial_cmp(&representative(b)).unwrap());
+
+        let total_addresses = Decimal::from(self.total_addresses);
+        let total_wealth: Decimal = ranges_sorted
+            .iter()
+            .map(|range| to_decimal(representative(range)) * Decimal::from(range.address_count))
+            .sum();
+
+        if total_wealth.is_zero() {
+            return 0.0;
+        }
+
+        let mut cumulative_addresses = Decimal::ZERO;
+        let mut cumulative_wealth = Decimal::ZERO;
+        let mut area = Decimal::ZERO;
+
+        for range in ranges_sorted {
+            let address_count = Decimal::from(range.address_count);
+            let range_wealth = to_decimal(representative(range)) * address_count;
+
+            let p_prev = cumulative_addresses;
+            let l_prev = cumulative_wealth;
+
+            cumulative_addresses += address_count.checked_div(total_addresses).unwrap_or(Decimal::ZERO);
+            cumulative_wealth += range_wealth.checked_div(total_wealth).unwrap_or(Decimal::ZERO);
+
+            area += (cumulative_addresses - p_prev) * (cumulative_wealth + l_prev) / Decimal::from(2);
+        }
+
+        let gini = Decimal::ONE - Decimal::from(2) * area;
+        gini.clamp(Decimal::ZERO, Decimal::ONE).to_f64().unwrap_or(0.0)
     }
 }
 
+/// Convert an `f64` to `Decimal`, falling back to zero for values `Decimal` can't represent
+/// (e.g. `NaN` or `infinity`), matching the convention used in `PercentileCalculator`
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or(Decimal::ZERO)
+}
+
 /// Calculate user's percentile based on Bitcoin distribution
 #[allow(dead_code)]
 pub fn calculate_percentile(
@@ -0,0 +1,58 @@
+/// A fiat currency the dashboard can convert Bitcoin holdings into for display. BTC remains the
+/// canonical stored value everywhere else; this only controls render-time conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Gbp,
+    Jpy,
+}
+
+impl Currency {
+    /// Lowercase ISO 4217-style code, as used by CoinGecko's `vs_currencies` query param
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Usd => "usd",
+            Currency::Eur => "eur",
+            Currency::Gbp => "gbp",
+            Currency::Jpy => "jpy",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Usd => "$",
+            Currency::Eur => "€",
+            Currency::Gbp => "£",
+            Currency::Jpy => "¥",
+        }
+    }
+
+    /// Decimal places conventionally shown for this currency (JPY has no minor unit in practice)
+    pub fn decimal_places(&self) -> usize {
+        match self {
+            Currency::Jpy => 0,
+            _ => 2,
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "usd" => Some(Currency::Usd),
+            "eur" => Some(Currency::Eur),
+            "gbp" => Some(Currency::Gbp),
+            "jpy" => Some(Currency::Jpy),
+            _ => None,
+        }
+    }
+
+    pub fn all() -> [Currency; 4] {
+        [Currency::Usd, Currency::Eur, Currency::Gbp, Currency::Jpy]
+    }
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Currency::Usd
+    }
+}
@@ -0,0 +1,4 @@
+pub mod api;
+pub mod bitcoin;
+pub mod currency;
+pub mod portfolio;
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// One labeled holding in a user's portfolio (e.g. "cold storage", "exchange"), tracked
+/// separately from the single manual-entry/address-lookup amount so multiple holdings can be
+/// combined into one aggregate percentile calculation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioEntry {
+    pub label: String,
+    pub btc_amount: f64,
+}
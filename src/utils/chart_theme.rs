@@ -1,6 +1,51 @@
 use plotters::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 use web_sys::window;
 
+/// A named, built-in chart palette, each defined for both light and dark mode (see
+/// [`MempoolChartTheme::for_palette`]). Selectable from [`crate::components::ui::theme_toggle::ThemeToggle`]
+/// and persisted via `ThemeManager::save_chart_theme_config`, independent of per-color
+/// [`ChartThemeConfig`] overrides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChartPalette {
+    /// The original Mempool.space-inspired palette.
+    Mempool,
+    /// High-contrast black/white with saturated primaries.
+    Classic,
+    /// Softer pastel tones.
+    Roundy,
+}
+
+impl ChartPalette {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChartPalette::Mempool => "mempool",
+            ChartPalette::Classic => "classic",
+            ChartPalette::Roundy => "roundy",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "mempool" => Some(ChartPalette::Mempool),
+            "classic" => Some(ChartPalette::Classic),
+            "roundy" => Some(ChartPalette::Roundy),
+            _ => None,
+        }
+    }
+
+    pub fn all() -> [ChartPalette; 3] {
+        [ChartPalette::Mempool, ChartPalette::Classic, ChartPalette::Roundy]
+    }
+}
+
+impl Default for ChartPalette {
+    fn default() -> Self {
+        ChartPalette::Mempool
+    }
+}
+
 /// Mempool.space inspired chart theme with Bitcoin orange accents
 #[derive(Clone, Debug)]
 pub struct MempoolChartTheme {
@@ -37,15 +82,48 @@ pub struct MempoolChartTheme {
     pub chart_warning: RGBColor,
     #[allow(dead_code)]
     pub chart_error: RGBColor,
+
+    /// Per-category overrides for [`Self::get_wealth_colors`], keyed by category name
+    /// (e.g. `"Whale"`). Populated from a user's [`ChartThemeConfig`]; empty by default.
+    pub wealth_color_overrides: HashMap<String, RGBColor>,
 }
 
 impl MempoolChartTheme {
     /// Create a new theme based on current CSS custom properties
     pub fn new() -> Self {
-        if is_dark_theme() {
-            Self::dark_theme()
-        } else {
-            Self::light_theme()
+        Self::for_palette(ChartPalette::default(), is_dark_theme())
+    }
+
+    /// Alias for [`Self::light_theme`], for callers that key off an explicit `Theme` value
+    pub fn light() -> Self {
+        Self::light_theme()
+    }
+
+    /// Alias for [`Self::dark_theme`], for callers that key off an explicit `Theme` value
+    pub fn dark() -> Self {
+        Self::dark_theme()
+    }
+
+    /// Build the active theme, preferring a user-saved [`ChartThemeConfig`] (see
+    /// `ThemeManager::get_stored_chart_theme_config`) over the ambient light/dark detection
+    pub fn current() -> Self {
+        match crate::utils::theme::ThemeManager::get_stored_chart_theme_config() {
+            Some(config) => config.resolve(),
+            None => Self::new(),
+        }
+    }
+
+    /// Resolve a named palette in the given mode. This is the single dispatch point every
+    /// built-in palette goes through, so adding a new named palette only means adding a
+    /// variant here and a pair of constructors.
+    pub fn for_palette(palette: ChartPalette, dark: bool) -> Self {
+        match (palette, dark) {
+            (ChartPalette::Mempool, true) => Self::dark_theme(),
+            (ChartPalette::Mempool, false) => Self::light_theme(),
+            (ChartPalette::Classic, true) => Self::classic_dark(),
+            (ChartPalette::Classic, false) => Self::classic_light(),
+            (ChartPalette::Roundy, true) => Self::roundy_dark(),
+            (ChartPalette::Roundy, false) => Self::roundy_light(),
         }
     }
 
@@ -79,6 +157,8 @@ impl MempoolChartTheme {
             chart_success: RGBColor(16, 185, 129), // #10b981 - success green
             chart_warning: RGBColor(245, 158, 11), // #f59e0b - warning orange
             chart_error: RGBColor(239, 68, 68),    // #ef4444 - error red
+
+            wealth_color_overrides: HashMap::new(),
         }
     }
 
@@ -112,6 +192,129 @@ impl MempoolChartTheme {
             chart_success: RGBColor(16, 185, 129), // #10b981
             chart_warning: RGBColor(245, 158, 11), // #f59e0b
             chart_error: RGBColor(239, 68, 68),    // #ef4444
+
+            wealth_color_overrides: HashMap::new(),
+        }
+    }
+
+    /// High-contrast "Classic" palette: pure black-on-white with saturated primaries, for
+    /// readers who find the softer Mempool palette too low-contrast.
+    pub fn classic_dark() -> Self {
+        Self {
+            background: RGBColor(0, 0, 0),
+            card_background: RGBColor(20, 20, 20),
+            tertiary_background: RGBColor(40, 40, 40),
+
+            text_primary: RGBColor(255, 255, 255),
+            text_secondary: RGBColor(230, 230, 230),
+            text_muted: RGBColor(170, 170, 170),
+
+            border_primary: RGBColor(255, 255, 255),
+            border_secondary: RGBColor(100, 100, 100),
+            grid_color: RGBColor(90, 90, 90),
+
+            bitcoin_orange: RGBColor(247, 147, 26),
+            bitcoin_orange_hover: RGBColor(255, 170, 51),
+            bitcoin_orange_muted: RGBColor(247, 147, 26),
+
+            chart_primary: RGBColor(247, 147, 26),
+            chart_secondary: RGBColor(0, 102, 255),
+            chart_accent: RGBColor(0, 204, 102),
+            chart_success: RGBColor(0, 204, 102),
+            chart_warning: RGBColor(255, 204, 0),
+            chart_error: RGBColor(255, 0, 0),
+
+            wealth_color_overrides: HashMap::new(),
+        }
+    }
+
+    /// High-contrast "Classic" palette, light mode: see [`Self::classic_dark`].
+    pub fn classic_light() -> Self {
+        Self {
+            background: RGBColor(255, 255, 255),
+            card_background: RGBColor(245, 245, 245),
+            tertiary_background: RGBColor(230, 230, 230),
+
+            text_primary: RGBColor(0, 0, 0),
+            text_secondary: RGBColor(30, 30, 30),
+            text_muted: RGBColor(90, 90, 90),
+
+            border_primary: RGBColor(0, 0, 0),
+            border_secondary: RGBColor(150, 150, 150),
+            grid_color: RGBColor(190, 190, 190),
+
+            bitcoin_orange: RGBColor(247, 147, 26),
+            bitcoin_orange_hover: RGBColor(214, 126, 13),
+            bitcoin_orange_muted: RGBColor(247, 147, 26),
+
+            chart_primary: RGBColor(247, 147, 26),
+            chart_secondary: RGBColor(0, 82, 204),
+            chart_accent: RGBColor(0, 153, 76),
+            chart_success: RGBColor(0, 153, 76),
+            chart_warning: RGBColor(204, 153, 0),
+            chart_error: RGBColor(204, 0, 0),
+
+            wealth_color_overrides: HashMap::new(),
+        }
+    }
+
+    /// Softer "Roundy" palette: muted pastel tones for a gentler, less clinical look.
+    pub fn roundy_dark() -> Self {
+        Self {
+            background: RGBColor(30, 27, 38),
+            card_background: RGBColor(43, 39, 53),
+            tertiary_background: RGBColor(58, 53, 71),
+
+            text_primary: RGBColor(237, 233, 245),
+            text_secondary: RGBColor(209, 202, 224),
+            text_muted: RGBColor(156, 148, 176),
+
+            border_primary: RGBColor(156, 148, 176),
+            border_secondary: RGBColor(90, 83, 110),
+            grid_color: RGBColor(74, 68, 92),
+
+            bitcoin_orange: RGBColor(242, 163, 97),
+            bitcoin_orange_hover: RGBColor(247, 181, 126),
+            bitcoin_orange_muted: RGBColor(242, 163, 97),
+
+            chart_primary: RGBColor(242, 163, 97),
+            chart_secondary: RGBColor(129, 161, 214),
+            chart_accent: RGBColor(139, 199, 163),
+            chart_success: RGBColor(139, 199, 163),
+            chart_warning: RGBColor(232, 178, 110),
+            chart_error: RGBColor(214, 123, 123),
+
+            wealth_color_overrides: HashMap::new(),
+        }
+    }
+
+    /// Softer "Roundy" palette, light mode: see [`Self::roundy_dark`].
+    pub fn roundy_light() -> Self {
+        Self {
+            background: RGBColor(253, 250, 246),
+            card_background: RGBColor(246, 240, 233),
+            tertiary_background: RGBColor(238, 229, 219),
+
+            text_primary: RGBColor(59, 51, 71),
+            text_secondary: RGBColor(91, 82, 107),
+            text_muted: RGBColor(139, 129, 153),
+
+            border_primary: RGBColor(224, 213, 230),
+            border_secondary: RGBColor(209, 196, 217),
+            grid_color: RGBColor(232, 222, 226),
+
+            bitcoin_orange: RGBColor(230, 140, 64),
+            bitcoin_orange_hover: RGBColor(207, 122, 51),
+            bitcoin_orange_muted: RGBColor(230, 140, 64),
+
+            chart_primary: RGBColor(230, 140, 64),
+            chart_secondary: RGBColor(102, 133, 196),
+            chart_accent: RGBColor(103, 168, 130),
+            chart_success: RGBColor(103, 168, 130),
+            chart_warning: RGBColor(204, 150, 73),
+            chart_error: RGBColor(193, 97, 97),
+
+            wealth_color_overrides: HashMap::new(),
         }
     }
 
@@ -124,16 +327,29 @@ impl MempoolChartTheme {
         ]
     }
 
-    /// Get wealth category colors (for distribution visualization)
+    /// Get wealth category colors (for distribution visualization), applying any
+    /// per-category overrides from [`Self::wealth_color_overrides`]
     pub fn get_wealth_colors(&self) -> Vec<(String, RGBColor)> {
-        vec![
+        let defaults = vec![
             ("Shrimp".to_string(), self.chart_accent),       // Green
             ("Crab".to_string(), self.chart_warning),        // Yellow/Orange
             ("Fish".to_string(), self.chart_secondary),      // Blue
             ("Dolphin".to_string(), RGBColor(139, 92, 246)), // Purple
             ("Shark".to_string(), RGBColor(236, 72, 153)),   // Pink
             ("Whale".to_string(), self.bitcoin_orange),      // Bitcoin Orange
-        ]
+        ];
+
+        defaults
+            .into_iter()
+            .map(|(name, color)| {
+                let resolved = self
+                    .wealth_color_overrides
+                    .get(&name)
+                    .copied()
+                    .unwrap_or(color);
+                (name, resolved)
+            })
+            .collect()
     }
 
     /// Create a styled text element with proper font
@@ -150,6 +366,73 @@ impl MempoolChartTheme {
     pub fn create_secondary_text_style(&self, size: i32) -> TextStyle<'static> {
         ("Inter", size).into_font().color(&self.text_secondary)
     }
+
+    /// Generate `n` visually distinct, stable colors by stepping hue uniformly around the color
+    /// wheel at a fixed saturation/value, so chart series with more segments than
+    /// `get_gradient_colors`/`get_wealth_colors` have entries for never repeat or clash. The
+    /// starting hue is seeded from `bitcoin_orange` so the first color stays on-brand.
+    pub fn palette(&self, n: usize) -> Vec<RGBColor> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let base_hue = rgb_to_hue(self.bitcoin_orange);
+        (0..n)
+            .map(|i| hsv_to_rgb(base_hue + i as f64 * 360.0 / n as f64, 0.7, 0.95))
+            .collect()
+    }
+}
+
+/// Hue (in degrees, `[0, 360)`) of an sRGB color, ignoring saturation/value
+fn rgb_to_hue(color: RGBColor) -> f64 {
+    let RGBColor(r, g, b) = color;
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return 0.0;
+    }
+
+    let hue = if max == r {
+        60.0 * (((g - b) / delta) % 6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    hue.rem_euclid(360.0)
+}
+
+/// Convert an HSV color (`h` in degrees, `s`/`v` in `[0, 1]`) to an opaque sRGB `RGBColor`
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> RGBColor {
+    let h = h.rem_euclid(360.0);
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = if h < 60.0 {
+        (c, x, 0.0)
+    } else if h < 120.0 {
+        (x, c, 0.0)
+    } else if h < 180.0 {
+        (0.0, c, x)
+    } else if h < 240.0 {
+        (0.0, x, c)
+    } else if h < 300.0 {
+        (x, 0.0, c)
+    } else {
+        (c, 0.0, x)
+    };
+
+    RGBColor(
+        ((r + m) * 255.0).round() as u8,
+        ((g + m) * 255.0).round() as u8,
+        ((b + m) * 255.0).round() as u8,
+    )
 }
 
 /// Check if dark theme is currently active by reading CSS custom properties
@@ -182,14 +465,13 @@ pub fn is_dark_theme() -> bool {
     false
 }
 
-/// Format large numbers in a human-readable way
+/// Format large numbers in a human-readable way. Values at or above 1,000 delegate to
+/// `formatters::format_large_number` for its full K/M/B/T/Q tier ladder, so chart axis labels and
+/// tooltips scale the same way the rest of the app does instead of topping out at "B"; values
+/// below that keep this function's own sub-1.0 precision tiers, which that helper doesn't need.
 pub fn format_large_number(value: f64) -> String {
-    if value >= 1_000_000_000.0 {
-        format!("{:.1}B", value / 1_000_000_000.0)
-    } else if value >= 1_000_000.0 {
-        format!("{:.1}M", value / 1_000_000.0)
-    } else if value >= 1_000.0 {
-        format!("{:.1}K", value / 1_000.0)
+    if value >= 1_000.0 {
+        crate::utils::formatters::format_large_number(value)
     } else if value >= 1.0 {
         format!("{:.0}", value)
     } else if value >= 0.001 {
@@ -212,6 +494,19 @@ pub fn format_bitcoin_amount(btc: f64) -> String {
     }
 }
 
+/// Format a fiat amount with a currency symbol prefix and human-scaled precision
+pub fn format_fiat_amount(value: f64, symbol: &str) -> String {
+    if value >= 1_000_000_000.0 {
+        format!("{}{:.2}B", symbol, value / 1_000_000_000.0)
+    } else if value >= 1_000_000.0 {
+        format!("{}{:.2}M", symbol, value / 1_000_000.0)
+    } else if value >= 1_000.0 {
+        format!("{}{:.2}K", symbol, value / 1_000.0)
+    } else {
+        format!("{}{:.2}", symbol, value)
+    }
+}
+
 /// Format percentile values
 pub fn format_percentile(percentile: f64) -> String {
     if percentile >= 99.0 {
@@ -222,3 +517,187 @@ pub fn format_percentile(percentile: f64) -> String {
         format!("{:.0}%", percentile)
     }
 }
+
+/// A CSS-style hex color (`#RRGGBB` opaque, or `#RRGGBBAA` with a trailing alpha byte) that
+/// (de)serializes directly to/from that hex string. The alpha byte is accepted but discarded
+/// on parse, since `MempoolChartTheme` only deals in opaque `RGBColor`s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HexColor(pub RGBColor);
+
+impl HexColor {
+    fn parse(value: &str) -> Result<Self, String> {
+        let digits = value.strip_prefix('#').unwrap_or(value);
+        if digits.len() != 6 && digits.len() != 8 {
+            return Err(format!(
+                "hex color '{}' must have 6 or 8 hex digits, got {}",
+                value,
+                digits.len()
+            ));
+        }
+
+        let parsed = u32::from_str_radix(digits, 16)
+            .map_err(|e| format!("hex color '{}' is not valid hex: {}", value, e))?;
+
+        let color = if digits.len() == 6 {
+            RGBColor((parsed >> 16) as u8, (parsed >> 8) as u8, parsed as u8)
+        } else {
+            RGBColor((parsed >> 24) as u8, (parsed >> 16) as u8, (parsed >> 8) as u8)
+        };
+
+        Ok(HexColor(color))
+    }
+
+    fn to_hex_string(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0 .0, self.0 .1, self.0 .2)
+    }
+}
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        HexColor::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for HexColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_hex_string())
+    }
+}
+
+/// A user-defined chart palette that overrides individual colors of a built-in theme. Any
+/// field left `None` falls back to the theme named by `extends` (a [`ChartPalette`] name such
+/// as `"classic"`/`"roundy"` resolved against the ambient light/dark mode, or the literal
+/// `"light"`/`"dark"` to pin the mode regardless of ambient detection; missing or unrecognized
+/// defaults to the ambient Mempool palette).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ChartThemeConfig {
+    #[serde(default)]
+    pub extends: Option<String>,
+
+    #[serde(default)]
+    pub background: Option<HexColor>,
+    #[serde(default)]
+    pub card_background: Option<HexColor>,
+    #[serde(default)]
+    pub tertiary_background: Option<HexColor>,
+    #[serde(default)]
+    pub text_primary: Option<HexColor>,
+    #[serde(default)]
+    pub text_secondary: Option<HexColor>,
+    #[serde(default)]
+    pub text_muted: Option<HexColor>,
+    #[serde(default)]
+    pub border_primary: Option<HexColor>,
+    #[serde(default)]
+    pub border_secondary: Option<HexColor>,
+    #[serde(default)]
+    pub grid_color: Option<HexColor>,
+    #[serde(default)]
+    pub bitcoin_orange: Option<HexColor>,
+    #[serde(default)]
+    pub bitcoin_orange_hover: Option<HexColor>,
+    #[serde(default)]
+    pub bitcoin_orange_muted: Option<HexColor>,
+    #[serde(default)]
+    pub chart_primary: Option<HexColor>,
+    #[serde(default)]
+    pub chart_secondary: Option<HexColor>,
+    #[serde(default)]
+    pub chart_accent: Option<HexColor>,
+    #[serde(default)]
+    pub chart_success: Option<HexColor>,
+    #[serde(default)]
+    pub chart_warning: Option<HexColor>,
+    #[serde(default)]
+    pub chart_error: Option<HexColor>,
+    /// Overrides for individual wealth-category colors, keyed by category name (e.g. `"Whale"`)
+    #[serde(default)]
+    pub wealth_colors: Option<HashMap<String, HexColor>>,
+}
+
+impl ChartThemeConfig {
+    /// Resolve this config into a concrete theme: start from the base named by `extends`,
+    /// then layer any overridden fields on top.
+    pub fn resolve(&self) -> MempoolChartTheme {
+        let mut theme = match self.extends.as_deref() {
+            Some("light") => MempoolChartTheme::light(),
+            Some("dark") => MempoolChartTheme::dark(),
+            Some(name) if ChartPalette::from_str(name).is_some() => {
+                MempoolChartTheme::for_palette(ChartPalette::from_str(name).unwrap(), is_dark_theme())
+            }
+            _ => MempoolChartTheme::new(),
+        };
+
+        if let Some(c) = self.background {
+            theme.background = c.0;
+        }
+        if let Some(c) = self.card_background {
+            theme.card_background = c.0;
+        }
+        if let Some(c) = self.tertiary_background {
+            theme.tertiary_background = c.0;
+        }
+        if let Some(c) = self.text_primary {
+            theme.text_primary = c.0;
+        }
+        if let Some(c) = self.text_secondary {
+            theme.text_secondary = c.0;
+        }
+        if let Some(c) = self.text_muted {
+            theme.text_muted = c.0;
+        }
+        if let Some(c) = self.border_primary {
+            theme.border_primary = c.0;
+        }
+        if let Some(c) = self.border_secondary {
+            theme.border_secondary = c.0;
+        }
+        if let Some(c) = self.grid_color {
+            theme.grid_color = c.0;
+        }
+        if let Some(c) = self.bitcoin_orange {
+            theme.bitcoin_orange = c.0;
+        }
+        if let Some(c) = self.bitcoin_orange_hover {
+            theme.bitcoin_orange_hover = c.0;
+        }
+        if let Some(c) = self.bitcoin_orange_muted {
+            theme.bitcoin_orange_muted = c.0;
+        }
+        if let Some(c) = self.chart_primary {
+            theme.chart_primary = c.0;
+        }
+        if let Some(c) = self.chart_secondary {
+            theme.chart_secondary = c.0;
+        }
+        if let Some(c) = self.chart_accent {
+            theme.chart_accent = c.0;
+        }
+        if let Some(c) = self.chart_success {
+            theme.chart_success = c.0;
+        }
+        if let Some(c) = self.chart_warning {
+            theme.chart_warning = c.0;
+        }
+        if let Some(c) = self.chart_error {
+            theme.chart_error = c.0;
+        }
+
+        if let Some(overrides) = &self.wealth_colors {
+            for (name, color) in overrides {
+                theme
+                    .wealth_color_overrides
+                    .insert(name.clone(), color.0);
+            }
+        }
+
+        theme
+    }
+}
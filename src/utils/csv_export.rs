@@ -0,0 +1,83 @@
+use crate::types::bitcoin::{BitcoinDistribution, PercentileResult};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Serialize a distribution's ranges to CSV, one row per `WealthRange` in the same column order
+/// as the struct's fields. `WealthRange` already derives `Serialize`, so this is a direct
+/// `csv::Writer::serialize` per range rather than a bespoke row type.
+pub fn distribution_to_csv(distribution: &BitcoinDistribution) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    for range in &distribution.ranges {
+        writer.serialize(range).map_err(|e| format!("CSV serialization error: {}", e))?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| format!("CSV writer error: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("CSV encoding error: {}", e))
+}
+
+/// `comparison_metrics` is an open-ended map rather than a fixed set of columns, so it's left out
+/// of the exported row; every other field of `PercentileResult` is a plain scalar and gets one.
+#[derive(Serialize)]
+struct PercentileResultRow<'a> {
+    user_bitcoin_amount: f64,
+    percentile: f64,
+    rank: u64,
+    addresses_below: u64,
+    addresses_above: u64,
+    wealth_category: &'a str,
+}
+
+/// Serialize a single percentile result to a one-row CSV, for taking a ranking snapshot into a
+/// spreadsheet.
+pub fn percentile_result_to_csv(result: &PercentileResult) -> Result<String, String> {
+    let row = PercentileResultRow {
+        user_bitcoin_amount: result.user_bitcoin_amount,
+        percentile: result.percentile,
+        rank: result.rank,
+        addresses_below: result.addresses_below,
+        addresses_above: result.addresses_above,
+        wealth_category: result.wealth_category.as_str(),
+    };
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.serialize(&row).map_err(|e| format!("CSV serialization error: {}", e))?;
+
+    let bytes = writer.into_inner().map_err(|e| format!("CSV writer error: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("CSV encoding error: {}", e))
+}
+
+/// Stitch the percentile result, wealth concentration analysis, and full distribution into one
+/// CSV, each as its own labeled section (blank line between), for a single "export everything"
+/// download rather than three separate files.
+pub fn full_result_to_csv(
+    result: Option<&PercentileResult>,
+    wealth_analysis: Option<&HashMap<String, f64>>,
+    distribution: Option<&BitcoinDistribution>,
+) -> Result<String, String> {
+    let mut sections = Vec::new();
+
+    if let Some(result) = result {
+        sections.push(format!("Percentile Result\n{}", percentile_result_to_csv(result)?));
+    }
+
+    if let Some(wealth_analysis) = wealth_analysis {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        let mut metrics: Vec<(&String, &f64)> = wealth_analysis.iter().collect();
+        metrics.sort_by(|a, b| a.0.cmp(b.0));
+        for (metric, value) in metrics {
+            writer
+                .serialize((metric, value))
+                .map_err(|e| format!("CSV serialization error: {}", e))?;
+        }
+        let bytes = writer.into_inner().map_err(|e| format!("CSV writer error: {}", e))?;
+        let csv = String::from_utf8(bytes).map_err(|e| format!("CSV encoding error: {}", e))?;
+        sections.push(format!("Wealth Concentration Analysis\n{}", csv));
+    }
+
+    if let Some(distribution) = distribution {
+        sections.push(format!("Wealth Distribution\n{}", distribution_to_csv(distribution)?));
+    }
+
+    Ok(sections.join("\n"))
+}
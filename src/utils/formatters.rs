@@ -1,32 +1,220 @@
 // Utility functions for formatting numbers and data
 // Only keeping functions that are actually used to eliminate warnings
 
+/// How a fractional value rounds to its final decimal places
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Halfway values round away from zero
+    HalfUp,
+    /// Halfway values round to the nearest even digit, avoiding systematic upward bias
+    HalfEven,
+    /// Digits past the requested precision are discarded rather than rounded
+    Truncate,
+}
+
+impl Default for RoundingMode {
+    fn default() -> Self {
+        RoundingMode::HalfUp
+    }
+}
+
+/// Separators, decimal place bounds, and rounding behavior for `NumberFormatter`. Mirrors
+/// `rusty-money`'s `Params` (which controls a `Money` value's rounding/separators/symbol
+/// placement), specialized here to plain numbers with no currency symbol.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormatParams {
+    pub thousands_separator: char,
+    pub decimal_separator: char,
+    pub min_decimal_places: usize,
+    pub max_decimal_places: usize,
+    pub rounding_mode: RoundingMode,
+}
+
+impl Default for NumberFormatParams {
+    /// US-style formatting with no decimal places, the same separators and precision
+    /// `format_number_with_commas` originally had fixed
+    fn default() -> Self {
+        Self {
+            thousands_separator: ',',
+            decimal_separator: '.',
+            min_decimal_places: 0,
+            max_decimal_places: 0,
+            rounding_mode: RoundingMode::default(),
+        }
+    }
+}
+
+impl NumberFormatParams {
+    pub fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    pub fn with_decimal_places(mut self, min: usize, max: usize) -> Self {
+        self.min_decimal_places = min;
+        self.max_decimal_places = max;
+        self
+    }
+
+    pub fn with_rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+}
+
+/// Locale-aware number formatting: grouping the integer part with a configurable thousands
+/// separator while leaving the fractional part ungrouped, per a `NumberFormatParams` config.
+/// `format_number_with_commas` is the default-params ("1,234.56"-style) shortcut.
+pub struct NumberFormatter;
+
+impl NumberFormatter {
+    pub fn format(value: f64, params: &NumberFormatParams) -> String {
+        let sign = if value < 0.0 { "-" } else { "" };
+        let rounded = Self::round_to_precision(value.abs(), params.max_decimal_places, params.rounding_mode);
+
+        let (int_part, frac_part) = match rounded.split_once('.') {
+            Some((int_part, frac_part)) => (int_part.to_string(), frac_part.to_string()),
+            None => (rounded, String::new()),
+        };
+
+        let int_part = group_thousands_with_separator(&int_part, params.thousands_separator);
+        let frac_part = Self::trim_to_bounds(frac_part, params.min_decimal_places, params.max_decimal_places);
+
+        if frac_part.is_empty() {
+            format!("{}{}", sign, int_part)
+        } else {
+            format!("{}{}{}{}", sign, int_part, params.decimal_separator, frac_part)
+        }
+    }
+
+    fn round_to_precision(value: f64, precision: usize, rounding_mode: RoundingMode) -> String {
+        match rounding_mode {
+            RoundingMode::HalfUp => {
+                let factor = 10f64.powi(precision as i32);
+                format!("{:.*}", precision, (value * factor).round() / factor)
+            }
+            RoundingMode::Truncate => {
+                let factor = 10f64.powi(precision as i32);
+                format!("{:.*}", precision, (value * factor).trunc() / factor)
+            }
+            RoundingMode::HalfEven => {
+                let factor = 10f64.powi(precision as i32);
+                let scaled = value * factor;
+                let floor = scaled.floor();
+                let is_exact_half = (scaled - floor - 0.5).abs() < f64::EPSILON;
+                let rounded = if is_exact_half {
+                    if (floor as i64) % 2 == 0 {
+                        floor
+                    } else {
+                        floor + 1.0
+                    }
+                } else {
+                    scaled.round()
+                };
+                format!("{:.*}", precision, rounded / factor)
+            }
+        }
+    }
+
+    /// Trim trailing zeros down to `min_places`, then pad back up to it if rounding removed too
+    /// many, capping the total length at `max_places`
+    fn trim_to_bounds(mut frac_part: String, min_places: usize, max_places: usize) -> String {
+        while frac_part.len() > min_places && frac_part.ends_with('0') {
+            frac_part.pop();
+        }
+        while frac_part.len() < min_places {
+            frac_part.push('0');
+        }
+        frac_part.truncate(max_places.max(min_places));
+        frac_part
+    }
+}
+
 /// Format numbers with commas for better readability
 pub fn format_number_with_commas(number: f64) -> String {
-    let mut result = String::new();
-    let number_str = format!("{:.0}", number);
-    let chars: Vec<char> = number_str.chars().collect();
-    
-    for (i, ch) in chars.iter().enumerate() {
-        if i > 0 && (chars.len() - i) % 3 == 0 {
-            result.push(',');
+    NumberFormatter::format(number, &NumberFormatParams::default())
+}
+
+/// Suffix tiers for `format_large_number_with_precision`, largest magnitude first so the first
+/// match wins
+const LARGE_NUMBER_TIERS: [(f64, &str); 5] = [
+    (1_000_000_000_000_000.0, "Q"),
+    (1_000_000_000_000.0, "T"),
+    (1_000_000_000.0, "B"),
+    (1_000_000.0, "M"),
+    (1_000.0, "K"),
+];
+
+/// Format large numbers with K/M/B/T/Q suffixes at the requested decimal `precision`. If
+/// rounding the scaled value at that precision would reach 1000 (e.g. 999.95B -> "1000.0B"),
+/// promotes to the next tier up instead ("1.0T") rather than displaying a misleading thousand.
+pub fn format_large_number_with_precision(number: f64, precision: usize) -> String {
+    let sign = if number < 0.0 { "-" } else { "" };
+    let magnitude = number.abs();
+
+    for (index, (threshold, suffix)) in LARGE_NUMBER_TIERS.iter().enumerate() {
+        if magnitude < *threshold {
+            continue;
         }
-        result.push(*ch);
+
+        let scaled = magnitude / threshold;
+        let rounded: f64 = format!("{:.*}", precision, scaled).parse().unwrap_or(scaled);
+
+        if rounded >= 1000.0 && index > 0 {
+            let (next_threshold, next_suffix) = LARGE_NUMBER_TIERS[index - 1];
+            return format!("{}{:.*}{}", sign, precision, magnitude / next_threshold, next_suffix);
+        }
+
+        return format!("{}{:.*}{}", sign, precision, scaled, suffix);
     }
-    
-    result
+
+    format!("{}{:.*}", sign, precision, magnitude)
 }
 
-/// Format large numbers with appropriate suffixes (K, M, B)
+/// Format large numbers with appropriate suffixes (K, M, B, T, Q) at one decimal place
 pub fn format_large_number(number: f64) -> String {
-    if number.abs() >= 1_000_000_000.0 {
-        format!("{:.1}B", number / 1_000_000_000.0)
-    } else if number.abs() >= 1_000_000.0 {
-        format!("{:.1}M", number / 1_000_000.0)
-    } else if number.abs() >= 1_000.0 {
-        format!("{:.1}K", number / 1_000.0)
+    format_large_number_with_precision(number, 1)
+}
+
+/// Render `number` in scientific notation with `precision` decimal places in the mantissa,
+/// e.g. 1234.0 at precision 3 -> "1.234e3"
+fn format_scientific(number: f64, precision: usize) -> String {
+    if number == 0.0 {
+        return format!("{:.*}e0", precision, 0.0);
+    }
+
+    let sign = if number < 0.0 { "-" } else { "" };
+    let magnitude = number.abs();
+    let exponent = magnitude.log10().floor() as i32;
+    let mantissa = magnitude / 10f64.powi(exponent);
+
+    format!("{}{:.*}e{}", sign, precision, mantissa, exponent)
+}
+
+/// Format `number` with K/M/B/T/Q suffix scaling while its magnitude falls within
+/// `[sci_low_cutoff, sci_high_cutoff]`, falling back to scientific notation outside that range.
+/// This matters for tiny per-satoshi fractions and astronomically large hash-rate-derived
+/// numbers, where suffix scaling either runs out (above Q) or would print a misleading "0.0". The
+/// choice between suffix and `e`-notation form is made on the unscaled magnitude; inside the range
+/// the number goes through the same tiers `format_large_number_with_precision` uses, and the
+/// mantissa keeps the requested `precision` either way.
+pub fn format_with_scientific_fallback(
+    number: f64,
+    sci_low_cutoff: f64,
+    sci_high_cutoff: f64,
+    precision: usize,
+) -> String {
+    let magnitude = number.abs();
+
+    if number == 0.0 || (magnitude >= sci_low_cutoff && magnitude <= sci_high_cutoff) {
+        format_large_number_with_precision(number, precision)
     } else {
-        format!("{:.0}", number)
+        format_scientific(number, precision)
     }
 }
 
@@ -42,4 +230,222 @@ pub fn format_rank(rank: u64) -> String {
     format!("{}{}", format_number_with_commas(rank as f64), suffix)
 }
 
+/// Group `value`'s integer part into thousands (optionally), round to `decimal_places`, and wrap
+/// with `prefix`/`suffix` — an `Intl.NumberFormat`-style helper for fiat display. Kept as the
+/// fixed US-separator, symbol-wrapping helper `format_currency_amount` builds on; reach for
+/// `NumberFormatter` instead when the separators themselves need to vary by locale.
+pub fn format_grouped(value: f64, thousands_grouping: bool, decimal_places: usize, prefix: &str, suffix: &str) -> String {
+    let sign = if value < 0.0 { "-" } else { "" };
+    let rounded = format!("{:.*}", decimal_places, value.abs());
+
+    let (int_part, frac_part) = match rounded.split_once('.') {
+        Some((int_part, frac_part)) => (int_part.to_string(), Some(frac_part.to_string())),
+        None => (rounded, None),
+    };
+
+    let int_part = if thousands_grouping {
+        group_thousands(&int_part)
+    } else {
+        int_part
+    };
+
+    let magnitude = match frac_part {
+        Some(frac_part) => format!("{}.{}", int_part, frac_part),
+        None => int_part,
+    };
+
+    format!("{}{}{}{}", sign, prefix, magnitude, suffix)
+}
+
+/// Insert a comma before every third digit from the right, e.g. "1234567" -> "1,234,567"
+fn group_thousands(digits: &str) -> String {
+    group_thousands_with_separator(digits, ',')
+}
+
+/// Insert `separator` before every third digit from the right, e.g. "1234567" -> "1,234,567"
+fn group_thousands_with_separator(digits: &str, separator: char) -> String {
+    let chars: Vec<char> = digits.chars().collect();
+    let mut result = String::new();
+
+    for (i, ch) in chars.iter().enumerate() {
+        if i > 0 && (chars.len() - i) % 3 == 0 {
+            result.push(separator);
+        }
+        result.push(*ch);
+    }
+
+    result
+}
+
+/// Format `btc_amount` converted at `btc_rate` (the BTC price denominated in `currency`) using
+/// that currency's symbol and conventional decimal precision
+pub fn format_currency_amount(btc_amount: f64, currency: crate::types::currency::Currency, btc_rate: f64) -> String {
+    format_grouped(btc_amount * btc_rate, true, currency.decimal_places(), currency.symbol(), "")
+}
+
+/// Denomination a BTC amount can be displayed in, from whole coins down to satoshis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitcoinUnit {
+    Btc,
+    /// Milli-bitcoin, 1e-3 BTC
+    MilliBtc,
+    /// Also known as μBTC, 1e-6 BTC
+    Bits,
+    /// 1e-8 BTC, Bitcoin's smallest unit
+    Satoshi,
+}
+
+impl BitcoinUnit {
+    fn scale(self) -> f64 {
+        match self {
+            BitcoinUnit::Btc => 1.0,
+            BitcoinUnit::MilliBtc => 1_000.0,
+            BitcoinUnit::Bits => 1_000_000.0,
+            BitcoinUnit::Satoshi => 100_000_000.0,
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            BitcoinUnit::Btc => "BTC",
+            BitcoinUnit::MilliBtc => "mBTC",
+            BitcoinUnit::Bits => "bits",
+            BitcoinUnit::Satoshi => "sats",
+        }
+    }
+
+    fn decimal_places(self) -> usize {
+        match self {
+            BitcoinUnit::Btc => 8,
+            BitcoinUnit::MilliBtc => 5,
+            BitcoinUnit::Bits => 2,
+            BitcoinUnit::Satoshi => 0,
+        }
+    }
+}
+
+/// Pick the most readable unit for `amount` BTC: satoshis for dust-sized amounts, bits/mBTC for
+/// sub-BTC holdings, and BTC itself once the amount reaches a whole coin
+fn auto_bitcoin_unit(amount: f64) -> BitcoinUnit {
+    let magnitude = amount.abs();
+
+    if magnitude >= 1.0 {
+        BitcoinUnit::Btc
+    } else if magnitude >= 0.001 {
+        BitcoinUnit::MilliBtc
+    } else if magnitude >= 0.000_001 {
+        BitcoinUnit::Bits
+    } else {
+        BitcoinUnit::Satoshi
+    }
+}
+
+/// Format a validated BTC `amount` in `unit`, or in whichever unit is most readable for its
+/// magnitude when `unit` is `None` ("auto" mode), e.g. 0.00001234 BTC -> "12.34 bits". Reuses
+/// `validate_bitcoin_amount`'s supply/NaN/negative guards, so no invalid amount can be formatted.
+pub fn format_bitcoin_unit_amount(amount: f64, unit: Option<BitcoinUnit>) -> Result<String, String> {
+    crate::utils::validators::validate_bitcoin_amount(amount)?;
+
+    let unit = unit.unwrap_or_else(|| auto_bitcoin_unit(amount));
+    let scaled = amount * unit.scale();
+    let params = NumberFormatParams::default().with_decimal_places(0, unit.decimal_places());
+
+    Ok(format!("{} {}", NumberFormatter::format(scaled, &params), unit.suffix()))
+}
+
+/// A scale-tier formatter built either directly or parsed from a compact spec string, so
+/// templates/config can declare a number format without recompiling. Backed by the same
+/// `NumberFormatter` grouping/rounding engine as `format_number_with_commas`, and the same
+/// ascending-tier scaling as `format_large_number_with_precision`, just with the base and
+/// suffixes configurable instead of fixed at 1000/K,M,B,T,Q.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formatter {
+    pub scale_base: f64,
+    pub suffixes: Vec<String>,
+    pub precision: usize,
+    pub thousands_separator: char,
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self {
+            scale_base: 1000.0,
+            suffixes: vec!["K".to_string(), "M".to_string(), "B".to_string(), "T".to_string(), "Q".to_string()],
+            precision: 1,
+            thousands_separator: ',',
+        }
+    }
+}
+
+impl Formatter {
+    /// Parse a spec of the form `[n/<base>]:.<precision>/<separator> <suffix>,<suffix>,...`, e.g.
+    /// `"[n/1000]:.2/, K,M,B,T"` for "divide by powers of 1000, 2 decimal places, ',' thousands
+    /// separator, K/M/B/T suffixes". Every section is optional and falls back to
+    /// `Formatter::default()`'s value for it, so `""` parses to the default formatter.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut formatter = Formatter::default();
+        let mut remaining = spec.trim();
+
+        if let Some(bracket_start) = remaining.find("[n/") {
+            let after_bracket = &remaining[bracket_start + "[n/".len()..];
+            let bracket_end = after_bracket
+                .find(']')
+                .ok_or_else(|| "unterminated scale directive, expected ']'".to_string())?;
+            let base_str = &after_bracket[..bracket_end];
+            formatter.scale_base = base_str
+                .parse()
+                .map_err(|_| format!("invalid scale base '{}'", base_str))?;
+            remaining = &after_bracket[bracket_end + 1..];
+        }
+
+        if let Some(after_colon) = remaining.strip_prefix(":.") {
+            let digit_count = after_colon.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digit_count == 0 {
+                return Err("precision directive ':.' must be followed by a digit".to_string());
+            }
+            formatter.precision = after_colon[..digit_count]
+                .parse()
+                .map_err(|_| format!("invalid precision '{}'", &after_colon[..digit_count]))?;
+            remaining = &after_colon[digit_count..];
+        }
+
+        if let Some(after_slash) = remaining.strip_prefix('/') {
+            let separator = after_slash
+                .chars()
+                .next()
+                .ok_or_else(|| "separator directive '/' must be followed by a character".to_string())?;
+            formatter.thousands_separator = separator;
+            remaining = &after_slash[separator.len_utf8()..];
+        }
+
+        let suffix_list = remaining.trim();
+        if !suffix_list.is_empty() {
+            formatter.suffixes = suffix_list.split(',').map(|suffix| suffix.trim().to_string()).collect();
+        }
+
+        Ok(formatter)
+    }
+
+    /// Render `value` through this formatter's scale tiers, routing the scaled magnitude through
+    /// `NumberFormatter::format` for grouping and rounding. Unlike
+    /// `format_large_number_with_precision`, a scaled magnitude that rounds up to 1000 is not
+    /// promoted to the next suffix: at precision 1, 999.95e9 renders as "1,000.0B", not "1.0T".
+    pub fn format(&self, value: f64) -> String {
+        let sign = if value < 0.0 { "-" } else { "" };
+        let magnitude = value.abs();
+        let params = NumberFormatParams::default()
+            .with_thousands_separator(self.thousands_separator)
+            .with_decimal_places(self.precision, self.precision);
+
+        for (index, suffix) in self.suffixes.iter().enumerate().rev() {
+            let threshold = self.scale_base.powi(index as i32 + 1);
+            if magnitude >= threshold {
+                return format!("{}{}{}", sign, NumberFormatter::format(magnitude / threshold, &params), suffix);
+            }
+        }
+
+        format!("{}{}", sign, NumberFormatter::format(magnitude, &params))
+    }
+}
+
 // All other unused formatter functions have been removed to eliminate warnings
@@ -0,0 +1,8 @@
+pub mod chart_theme;
+pub mod csv_export;
+pub mod formatters;
+pub mod percentile_history;
+pub mod portfolio_storage;
+pub mod qr_code;
+pub mod theme;
+pub mod validators;
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use web_sys::window;
+
+/// How many past snapshots are kept per amount before the oldest is evicted
+const MAX_SNAPSHOTS_PER_AMOUNT: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PercentileSnapshot {
+    timestamp_ms: f64,
+    percentile: f64,
+}
+
+/// Owns localStorage persistence for a rolling, per-amount history of percentile snapshots, the
+/// same JSON-in-localStorage approach `PortfolioStorage` uses. Every amount the user has computed
+/// a percentile for gets its own capped log, keyed on the amount itself (so switching amounts
+/// doesn't mix unrelated trendlines), so `TrendlineChart` can plot how a specific holding's
+/// standing has moved over time instead of only ever showing `flat_placeholder_history`.
+pub struct PercentileHistoryStorage;
+
+impl PercentileHistoryStorage {
+    const STORAGE_KEY: &'static str = "bitcoin-wealth-percentile-history";
+
+    fn amount_key(amount: f64) -> String {
+        format!("{:.8}", amount)
+    }
+
+    fn load_all() -> HashMap<String, Vec<PercentileSnapshot>> {
+        Self::try_load_all().unwrap_or_default()
+    }
+
+    fn try_load_all() -> Option<HashMap<String, Vec<PercentileSnapshot>>> {
+        let window = window()?;
+        let local_storage = window.local_storage().ok()??;
+        let raw = local_storage.get_item(Self::STORAGE_KEY).ok()??;
+        serde_json::from_str(&raw).ok()
+    }
+
+    fn save_all(all: &HashMap<String, Vec<PercentileSnapshot>>) -> Result<(), wasm_bindgen::JsValue> {
+        let window = window().ok_or("No window available")?;
+        let local_storage = window
+            .local_storage()
+            .map_err(|_| "Failed to get localStorage")?
+            .ok_or("localStorage not available")?;
+
+        let serialized =
+            serde_json::to_string(all).map_err(|_| "Failed to serialize percentile history")?;
+
+        local_storage
+            .set_item(Self::STORAGE_KEY, &serialized)
+            .map_err(|_| "Failed to save percentile history to localStorage")?;
+
+        Ok(())
+    }
+
+    /// Record a new snapshot for `amount` at the current time, evicting the oldest snapshot for
+    /// that amount first if already at `MAX_SNAPSHOTS_PER_AMOUNT`.
+    pub fn record(amount: f64, percentile: f64) {
+        let mut all = Self::load_all();
+        let snapshots = all.entry(Self::amount_key(amount)).or_default();
+
+        snapshots.push(PercentileSnapshot {
+            timestamp_ms: js_sys::Date::now(),
+            percentile,
+        });
+        if snapshots.len() > MAX_SNAPSHOTS_PER_AMOUNT {
+            snapshots.remove(0);
+        }
+
+        let _ = Self::save_all(&all);
+    }
+
+    /// The recorded history for `amount`, oldest first, as `(period_label, percentile)` pairs
+    /// ("Now" for the most recent snapshot, "Ns/Nm/Nh/Nd ago" for earlier ones). Returns `None`
+    /// when nothing has been recorded for this amount yet, so the caller can fall back to a
+    /// placeholder instead of plotting a single-point "history".
+    pub fn history_for(amount: f64) -> Option<Vec<(String, f64)>> {
+        let all = Self::load_all();
+        let snapshots = all.get(&Self::amount_key(amount))?;
+
+        if snapshots.is_empty() {
+            return None;
+        }
+
+        let now = js_sys::Date::now();
+        let last_index = snapshots.len() - 1;
+
+        Some(
+            snapshots
+                .iter()
+                .enumerate()
+                .map(|(index, snapshot)| {
+                    let label = if index == last_index {
+                        "Now".to_string()
+                    } else {
+                        Self::elapsed_label(now - snapshot.timestamp_ms)
+                    };
+                    (label, snapshot.percentile)
+                })
+                .collect(),
+        )
+    }
+
+    /// Render an elapsed duration (in ms) as the coarsest unit that keeps the label short, e.g.
+    /// "45s ago", "12m ago", "3h ago", "2d ago"
+    fn elapsed_label(elapsed_ms: f64) -> String {
+        let elapsed_secs = (elapsed_ms / 1000.0).max(0.0) as u64;
+
+        if elapsed_secs < 60 {
+            format!("{}s ago", elapsed_secs)
+        } else if elapsed_secs < 3_600 {
+            format!("{}m ago", elapsed_secs / 60)
+        } else if elapsed_secs < 86_400 {
+            format!("{}h ago", elapsed_secs / 3_600)
+        } else {
+            format!("{}d ago", elapsed_secs / 86_400)
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::types::portfolio::PortfolioEntry;
+use wasm_bindgen::JsValue;
+use web_sys::window;
+
+/// Owns `localStorage` persistence for the user's labeled portfolio entries, the same
+/// JSON-in-localStorage approach `ThemeManager` uses for custom chart palettes
+pub struct PortfolioStorage;
+
+impl PortfolioStorage {
+    const STORAGE_KEY: &'static str = "bitcoin-wealth-portfolio";
+
+    /// Load the saved portfolio, or an empty one if nothing has been saved yet
+    pub fn load() -> Vec<PortfolioEntry> {
+        Self::try_load().unwrap_or_default()
+    }
+
+    fn try_load() -> Option<Vec<PortfolioEntry>> {
+        let window = window()?;
+        let local_storage = window.local_storage().ok()??;
+        let raw = local_storage.get_item(Self::STORAGE_KEY).ok()??;
+        serde_json::from_str(&raw).ok()
+    }
+
+    /// Save the portfolio entries as JSON in localStorage
+    pub fn save(entries: &[PortfolioEntry]) -> Result<(), JsValue> {
+        let window = window().ok_or("No window available")?;
+        let local_storage = window
+            .local_storage()
+            .map_err(|_| "Failed to get localStorage")?
+            .ok_or("localStorage not available")?;
+
+        let serialized =
+            serde_json::to_string(entries).map_err(|_| "Failed to serialize portfolio entries")?;
+
+        local_storage
+            .set_item(Self::STORAGE_KEY, &serialized)
+            .map_err(|_| "Failed to save portfolio to localStorage")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use qrcode::render::svg;
+use qrcode::QrCode;
+
+/// Render `data` (e.g. a shareable deep-link URL) as an inline SVG QR code, scaled up to
+/// roughly `size`x`size` pixels
+pub fn render_qr_svg(data: &str, size: u32) -> Result<String, String> {
+    let code = QrCode::new(data).map_err(|e| format!("Failed to encode QR code: {}", e))?;
+
+    Ok(code
+        .render()
+        .min_dimensions(size, size)
+        .dark_color(svg::Color("#000000"))
+        .light_color(svg::Color("#ffffff"))
+        .build())
+}
@@ -1,6 +1,8 @@
+use crate::utils::chart_theme::ChartThemeConfig;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
-use web_sys::window;
+use wasm_bindgen::JsCast;
+use web_sys::{window, MediaQueryListEvent, StorageEvent};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Theme {
@@ -38,10 +40,13 @@ impl Default for Theme {
     }
 }
 
+/// Owns theme persistence and OS-preference detection so `Header`/`ThemeToggle` and the chart
+/// theme (`crate::utils::chart_theme`, via `is_dark_theme`) read the same resolved value.
 pub struct ThemeManager;
 
 impl ThemeManager {
-    const STORAGE_KEY: &'static str = "bitcoin-wealth-theme";
+    const STORAGE_KEY: &'static str = "color-theme";
+    const CHART_THEME_STORAGE_KEY: &'static str = "bitcoin-wealth-chart-theme";
 
     /// Get the user's preferred theme from localStorage
     pub fn get_stored_theme() -> Option<Theme> {
@@ -66,16 +71,61 @@ impl ThemeManager {
         Ok(())
     }
 
-    /// Detect system theme preference
+    /// Clear the user's explicitly stored theme preference, so `watch_system_theme` resumes
+    /// following OS changes again (it otherwise stays silent once a preference is stored)
+    pub fn clear_stored_theme() -> Result<(), JsValue> {
+        let window = window().ok_or("No window available")?;
+        let local_storage = window
+            .local_storage()
+            .map_err(|_| "Failed to get localStorage")?
+            .ok_or("localStorage not available")?;
+
+        local_storage
+            .remove_item(Self::STORAGE_KEY)
+            .map_err(|_| "Failed to clear stored theme")?;
+
+        Ok(())
+    }
+
+    /// Get the user's saved custom chart palette, if any
+    pub fn get_stored_chart_theme_config() -> Option<ChartThemeConfig> {
+        let window = window()?;
+        let local_storage = window.local_storage().ok()??;
+        let raw = local_storage
+            .get_item(Self::CHART_THEME_STORAGE_KEY)
+            .ok()??;
+        serde_json::from_str(&raw).ok()
+    }
+
+    /// Save a custom chart palette as JSON in localStorage
+    pub fn save_chart_theme_config(config: &ChartThemeConfig) -> Result<(), JsValue> {
+        let window = window().ok_or("No window available")?;
+        let local_storage = window
+            .local_storage()
+            .map_err(|_| "Failed to get localStorage")?
+            .ok_or("localStorage not available")?;
+
+        let serialized = serde_json::to_string(config)
+            .map_err(|_| "Failed to serialize chart theme config")?;
+
+        local_storage
+            .set_item(Self::CHART_THEME_STORAGE_KEY, &serialized)
+            .map_err(|_| "Failed to save chart theme config to localStorage")?;
+
+        Ok(())
+    }
+
+    /// Detect system theme preference via `prefers-color-scheme`
     pub fn get_system_theme() -> Theme {
-        let _window = match window() {
+        let window = match window() {
             Some(w) => w,
             None => return Theme::Light,
         };
 
-        // For now, default to light theme
-        // TODO: Implement proper media query detection when needed
-        Theme::Light
+        match window.match_media("(prefers-color-scheme: dark)") {
+            Ok(Some(query)) if query.matches() => Theme::Dark,
+            _ => Theme::Light,
+        }
     }
 
     /// Get the initial theme (stored preference > system preference > light)
@@ -83,6 +133,58 @@ impl ThemeManager {
         Self::get_stored_theme().unwrap_or_else(|| Self::get_system_theme())
     }
 
+    /// Start listening for OS color-scheme changes and invoke `on_change` with the new system
+    /// theme whenever one fires. Changes are only honored while the user has no explicitly
+    /// stored preference (see `save_theme`) — once they toggle the theme themselves, system
+    /// changes are ignored until the stored preference is cleared. The listener is leaked for
+    /// the lifetime of the page, since it needs to stay alive for as long as the app runs.
+    pub fn watch_system_theme(on_change: impl Fn(Theme) + 'static) -> Result<(), JsValue> {
+        let window = window().ok_or("No window available")?;
+        let query = window
+            .match_media("(prefers-color-scheme: dark)")
+            .map_err(|_| "Failed to query prefers-color-scheme")?
+            .ok_or("matchMedia not supported")?;
+
+        let closure = Closure::<dyn Fn(MediaQueryListEvent)>::new(move |event: MediaQueryListEvent| {
+            if Self::get_stored_theme().is_some() {
+                return;
+            }
+
+            let theme = if event.matches() { Theme::Dark } else { Theme::Light };
+            on_change(theme);
+        });
+
+        query.set_onchange(Some(closure.as_ref().unchecked_ref()));
+        closure.forget();
+
+        Ok(())
+    }
+
+    /// Start listening for `color-theme` changing in another browser tab. `localStorage` only
+    /// fires a `storage` event on tabs *other* than the one that made the write, so this is what
+    /// keeps every open tab's theme in sync the moment the user toggles it in one of them. The
+    /// listener is leaked for the lifetime of the page, the same as `watch_system_theme`.
+    pub fn watch_storage_changes(on_change: impl Fn(Theme) + 'static) -> Result<(), JsValue> {
+        let window = window().ok_or("No window available")?;
+
+        let closure = Closure::<dyn Fn(StorageEvent)>::new(move |event: StorageEvent| {
+            if event.key().as_deref() != Some(Self::STORAGE_KEY) {
+                return;
+            }
+
+            if let Some(theme) = event.new_value().and_then(|value| Theme::from_string(&value)) {
+                on_change(theme);
+            }
+        });
+
+        window
+            .add_event_listener_with_callback("storage", closure.as_ref().unchecked_ref())
+            .map_err(|_| "Failed to register storage listener")?;
+        closure.forget();
+
+        Ok(())
+    }
+
     /// Apply theme to document with optimized performance and no hanging
     pub fn apply_theme(theme: Theme) -> Result<(), JsValue> {
         let window = window().ok_or("No window available")?;
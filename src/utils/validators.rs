@@ -18,4 +18,54 @@ pub fn validate_bitcoin_amount(amount: f64) -> Result<(), String> {
     Ok(())
 }
 
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Validate that `address` is shaped like a Bitcoin address, without a network round-trip.
+///
+/// This only checks format (prefix, length, and alphabet), the same level of validation a wallet
+/// does before it bothers submitting an address to an explorer: legacy/P2SH addresses are base58
+/// and start with `1`/`3`, native SegWit addresses are bech32/bech32m and start with `bc1` (or
+/// `tb1` on testnet). It does not verify the base58 checksum or bech32 polymod, since neither
+/// crate is a dependency here - callers still need to handle "no such address" from the explorer
+/// response itself.
+pub fn validate_bitcoin_address(address: &str) -> Result<(), String> {
+    if address.is_empty() {
+        return Err("Address cannot be empty".to_string());
+    }
+
+    if let Some(body) = address.strip_prefix("bc1").or_else(|| address.strip_prefix("tb1")) {
+        if !(14..=74).contains(&address.len()) {
+            return Err(format!("'{}' is not a valid length for a bech32 address", address));
+        }
+
+        if body.chars().any(|c| c.is_ascii_uppercase()) && body.chars().any(|c| c.is_ascii_lowercase()) {
+            return Err(format!("'{}' mixes upper- and lowercase, which bech32 addresses cannot do", address));
+        }
+
+        if !body.chars().all(|c| BECH32_CHARSET.contains(c.to_ascii_lowercase())) {
+            return Err(format!("'{}' contains characters outside the bech32 alphabet", address));
+        }
+
+        return Ok(());
+    }
+
+    if address.starts_with('1') || address.starts_with('3') {
+        if !(26..=35).contains(&address.len()) {
+            return Err(format!("'{}' is not a valid length for a base58 address", address));
+        }
+
+        if !address.chars().all(|c| BASE58_ALPHABET.contains(c)) {
+            return Err(format!("'{}' contains characters outside the base58 alphabet", address));
+        }
+
+        return Ok(());
+    }
+
+    Err(format!(
+        "'{}' doesn't look like a Bitcoin address (expected base58 1.../3... or bech32 bc1...)",
+        address
+    ))
+}
+
 // All other unused validator functions have been removed to eliminate warnings